@@ -0,0 +1,110 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use sentence_segmentation::processor;
+
+const ONE_MEGABYTE: usize = 1024 * 1024;
+
+const ENGLISH_SENTENCES: [&str; 6] = [
+    "The committee met on Tuesday to discuss the new budget proposal.",
+    "Did anyone expect the vote to be this close?",
+    "Analysts said the decision would affect \"thousands of families\" across the region.",
+    "Visit www.example.com for the full report (published in March).",
+    "It was, by all accounts, a remarkable turn of events!",
+    "Officials declined to comment... but the numbers speak for themselves.",
+];
+
+const JAPANESE_SENTENCES: [&str; 6] = [
+    "委員会は火曜日に新しい予算案について話し合った。",
+    "誰がこれほどの接戦を予想しただろうか？",
+    "専門家は「多くの家庭に影響する」と述べた。",
+    "詳しくはwww.example.comを参照してください。",
+    "それは誰にとっても驚くべき展開だった！",
+    "当局はコメントを控えたが、数字がすべてを物語っている。",
+];
+
+const ARABIC_SENTENCES: [&str; 6] = [
+    "اجتمعت اللجنة يوم الثلاثاء لمناقشة مقترح الميزانية الجديدة.",
+    "هل توقع أحد أن يكون التصويت متقاربا إلى هذا الحد؟",
+    "قال المحللون إن القرار سيؤثر على \"آلاف الأسر\" في المنطقة.",
+    "قم بزيارة www.example.com للاطلاع على التقرير الكامل.",
+    "لقد كان تحولا ملحوظا في الأحداث!",
+    "رفض المسؤولون التعليق، لكن الأرقام تتحدث عن نفسها.",
+];
+
+#[cfg(feature = "thai")]
+const THAI_SENTENCES: [&str; 6] = [
+    "คณะกรรมการประชุมกันเมื่อวันอังคารเพื่อหารือเรื่องงบประมาณใหม่",
+    "มีใครคาดคิดบ้างว่าการลงคะแนนจะสูสีขนาดนี้",
+    "นักวิเคราะห์กล่าวว่าการตัดสินใจครั้งนี้จะส่งผลต่อครอบครัวหลายพันครอบครัว",
+    "ดูรายงานฉบับเต็มได้ที่ www.example.com",
+    "นับเป็นเหตุการณ์ที่น่าทึ่งอย่างยิ่ง",
+    "เจ้าหน้าที่ปฏิเสธที่จะให้ความเห็น แต่ตัวเลขก็บอกทุกอย่างแล้ว",
+];
+
+// Builds a document of at least `min_bytes` by cycling through the sample sentences.
+fn generate(sentences: &[&str], min_bytes: usize, separator: &str) -> String {
+    let mut text = String::with_capacity(min_bytes + 256);
+    for sentence in sentences.iter().cycle() {
+        if !text.is_empty() && text.len() >= min_bytes {
+            break;
+        }
+        if !text.is_empty() {
+            text.push_str(separator);
+        }
+        text.push_str(sentence);
+    }
+    text
+}
+
+fn inputs(sentences: &[&str], separator: &str) -> Vec<(&'static str, String)> {
+    vec![
+        ("tweet", generate(&sentences[..2], 0, separator)),
+        ("news_article", generate(sentences, 4 * 1024, separator)),
+        ("document_1mb", generate(sentences, ONE_MEGABYTE, separator)),
+    ]
+}
+
+fn bench_language(c: &mut Criterion, name: &str, segment: fn(&str) -> Vec<String>, sentences: &[&str], separator: &str) {
+    let mut group = c.benchmark_group(name);
+    for (label, text) in inputs(sentences, separator) {
+        if label == "document_1mb" {
+            group.sample_size(10);
+        }
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(label), &text, |b, text| {
+            b.iter(|| segment(black_box(text)))
+        });
+    }
+    group.finish();
+}
+
+fn english(c: &mut Criterion) {
+    bench_language(c, "english", processor::english, &ENGLISH_SENTENCES, " ");
+}
+
+fn japanese(c: &mut Criterion) {
+    bench_language(c, "japanese", processor::japanese, &JAPANESE_SENTENCES, "");
+}
+
+fn arabic(c: &mut Criterion) {
+    bench_language(c, "arabic", processor::arabic, &ARABIC_SENTENCES, " ");
+}
+
+#[cfg(feature = "thai")]
+fn thai(c: &mut Criterion) {
+    bench_language(c, "thai", processor::thai, &THAI_SENTENCES, " ");
+}
+
+// An almost empty input isolates the fixed cost of a call, which reuses the segmenter compiled
+// by the first call, and of compiling the rule regexes of a new segmenter.
+fn per_call_overhead(c: &mut Criterion) {
+    c.bench_function("per_call_overhead", |b| b.iter(|| processor::english(black_box("Hi."))));
+    c.bench_function("segmenter_construction", |b| {
+        b.iter(|| processor::Segmenter::new(black_box(processor::config::english())).unwrap())
+    });
+}
+
+#[cfg(not(feature = "thai"))]
+criterion_group!(benches, per_call_overhead, english, japanese, arabic);
+#[cfg(feature = "thai")]
+criterion_group!(benches, per_call_overhead, english, japanese, arabic, thai);
+criterion_main!(benches);
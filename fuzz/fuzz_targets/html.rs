@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sentence_segmentation::processor::{config, Segmenter};
+
+// Arbitrary HTML, segmented in English.
+fuzz_target!(|html: &str| {
+    let segmenter = Segmenter::new(config::english()).unwrap();
+    for sentence in segmenter.segment_html(html).unwrap() {
+        assert!(sentence.span.end <= html.len(), "span out of bounds");
+    }
+});
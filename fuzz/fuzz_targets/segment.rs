@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sentence_segmentation::processor;
+
+// The first byte picks the language, the rest is the text to segment.
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, rest)) = data.split_first() else {
+        return;
+    };
+    let Ok(text) = std::str::from_utf8(rest) else {
+        return;
+    };
+    let language = &processor::REGISTRY[selector as usize % processor::REGISTRY.len()];
+    let name = language.name;
+    for sentence in language.segment(text).unwrap() {
+        assert!(!sentence.is_empty(), "{name}: empty sentence");
+        assert_eq!(sentence.trim(), sentence, "{name}: untrimmed sentence");
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sentence_segmentation::processor::{config, Segmenter};
+
+// Arbitrary SRT or WebVTT, segmented in English; invalid cue timings are errors, not panics.
+fuzz_target!(|subtitles: &str| {
+    let segmenter = Segmenter::new(config::english()).unwrap();
+    if let Ok(sentences) = segmenter.segment_subtitles(subtitles) {
+        for timed in sentences {
+            assert!(timed.sentence.span.end <= subtitles.len(), "span out of bounds");
+        }
+    }
+});
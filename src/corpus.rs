@@ -0,0 +1,70 @@
+//! Writers that turn segmented documents into training corpora.
+
+use crate::processor::Sentence;
+use std::io::{self, Write};
+
+/// Writes documents as CoNLL-U skeletons: a `# sent_id` and `# text` comment per sentence and
+/// one row per white-space separated token, with every annotation column left empty (`_`).
+///
+/// Sentence IDs are `<document id>-<n>`, where `n` counts the sentences of the document from 1,
+/// so they only change when the document or its segmentation does.
+pub struct ConlluWriter<W: Write> {
+    out: W,
+}
+
+impl<W: Write> ConlluWriter<W> {
+    pub fn new(out: W) -> Self {
+        ConlluWriter { out }
+    }
+
+    /// Writes the sentences of one document, preceded by a `# newdoc id` comment.
+    pub fn write_document(&mut self, document_id: &str, sentences: &[Sentence]) -> io::Result<()> {
+        writeln!(self.out, "# newdoc id = {}", single_line(document_id))?;
+        let sentences = sentences.iter().filter(|sentence| !sentence.text.trim().is_empty());
+        for (index, sentence) in sentences.enumerate() {
+            writeln!(self.out, "# sent_id = {}-{}", single_line(document_id), index + 1)?;
+            writeln!(self.out, "# text = {}", single_line(&sentence.text))?;
+            for (id, token) in sentence.text.split_whitespace().enumerate() {
+                writeln!(self.out, "{}\t{}\t_\t_\t_\t_\t_\t_\t_\t_", id + 1, token)?;
+            }
+            writeln!(self.out)?;
+        }
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+/// Writes one sentence per line, with an empty line between documents.
+pub struct LineWriter<W: Write> {
+    out: W,
+    documents: usize,
+}
+
+impl<W: Write> LineWriter<W> {
+    pub fn new(out: W) -> Self {
+        LineWriter { out, documents: 0 }
+    }
+
+    pub fn write_document(&mut self, sentences: &[Sentence]) -> io::Result<()> {
+        if self.documents > 0 {
+            writeln!(self.out)?;
+        }
+        self.documents += 1;
+        for sentence in sentences.iter().filter(|sentence| !sentence.text.trim().is_empty()) {
+            writeln!(self.out, "{}", single_line(&sentence.text))?;
+        }
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+// line breaks and tabs would break both formats
+fn single_line(text: &str) -> String {
+    text.trim().replace(['\r', '\n', '\t'], " ")
+}
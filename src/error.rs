@@ -0,0 +1,39 @@
+use std::fmt;
+
+/// Errors returned by the fallible segmentation API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// `alphabets` of a language configuration is not a usable character class body.
+    InvalidAlphabet { alphabets: String, message: String },
+    /// A punctuation field of a language configuration is not exactly one character.
+    InvalidPunctuation(String),
+    /// A rule built from a language configuration failed to compile.
+    InvalidPattern { pattern: String, message: String },
+    /// A rule failed while running over the text, e.g. by exceeding the backtracking limit.
+    Matching(String),
+    /// The Thai model could not be loaded or evaluated.
+    Model(String),
+    /// No built-in language has the given name or ISO 639 code.
+    UnknownLanguage(String),
+    /// A quotation pair of a language configuration is not two different characters.
+    InvalidQuotes(String),
+    /// A line of a subtitle file could not be parsed.
+    InvalidSubtitle { line: usize, message: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidAlphabet { alphabets, message } => write!(f, "invalid alphabets {:?}: {}", alphabets, message),
+            Error::InvalidPunctuation(punctuation) => write!(f, "punctuation {:?} is not a single character", punctuation),
+            Error::InvalidPattern { pattern, message } => write!(f, "invalid pattern {:?}: {}", pattern, message),
+            Error::Matching(message) => write!(f, "matching failed: {}", message),
+            Error::Model(message) => write!(f, "thai model failed: {}", message),
+            Error::UnknownLanguage(language) => write!(f, "unknown language {:?}", language),
+            Error::InvalidQuotes(pair) => write!(f, "quotation pair {:?} is not an opening and a closing character", pair),
+            Error::InvalidSubtitle { line, message } => write!(f, "invalid subtitle at line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
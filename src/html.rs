@@ -0,0 +1,173 @@
+use crate::tracked::Blocks;
+
+// elements whose start and end tags end a block of text
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "body", "br", "caption", "dd", "details", "dialog", "div", "dl", "dt",
+    "fieldset", "figcaption", "figure", "footer", "form", "h1", "h2", "h3", "h4", "h5", "h6", "header", "hr", "html",
+    "legend", "li", "main", "nav", "ol", "option", "p", "pre", "section", "summary", "table", "tbody", "td", "tfoot",
+    "th", "thead", "tr", "ul",
+];
+
+// elements whose content is not visible text, skipped up to their end tag
+const HIDDEN_ELEMENTS: &[&str] = &["script", "style", "noscript", "template", "title", "svg", "math"];
+
+const ENTITIES: &[(&str, &str)] = &[
+    ("amp", "&"), ("lt", "<"), ("gt", ">"), ("quot", "\""), ("apos", "'"), ("nbsp", "\u{00A0}"),
+    ("ndash", "–"), ("mdash", "—"), ("hellip", "…"), ("lsquo", "‘"), ("rsquo", "’"), ("sbquo", "‚"),
+    ("ldquo", "“"), ("rdquo", "”"), ("bdquo", "„"), ("laquo", "«"), ("raquo", "»"), ("iexcl", "¡"),
+    ("iquest", "¿"), ("middot", "·"), ("bull", "•"), ("copy", "©"), ("reg", "®"), ("trade", "™"),
+    ("deg", "°"), ("times", "×"), ("divide", "÷"), ("euro", "€"), ("pound", "£"), ("yen", "¥"),
+    ("cent", "¢"), ("sect", "§"), ("para", "¶"), ("shy", "\u{00AD}"), ("thinsp", "\u{2009}"),
+    ("ensp", "\u{2002}"), ("emsp", "\u{2003}"), ("zwnj", "\u{200C}"), ("zwj", "\u{200D}"),
+];
+
+// longest entity name scanned for its `;`, e.g. `CounterClockwiseContourIntegral`; a longer
+// one is text, so that a run of `&` costs linear time
+const MAX_ENTITY_LENGTH: usize = 32;
+
+// longest tag or declaration scanned for its `>`; a longer one is text
+const MAX_TAG_LENGTH: usize = 4096;
+
+struct Tag {
+    name: String,
+    closing: bool,
+    self_closing: bool,
+    length: usize,
+}
+
+/// Collects the visible text of an HTML document into blocks, decoding entities. Offsets of
+/// the blocks point into the HTML source.
+pub(crate) fn blocks(html: &str) -> Blocks {
+    let mut blocks = Blocks::default();
+    let mut position = 0;
+    let mut pre_depth = 0usize;
+
+    while let Some(offset) = html[position..].find(['<', '&']) {
+        let index = position + offset;
+        push_text(&mut blocks, html, position, index, pre_depth > 0);
+        let rest = &html[index..];
+
+        if rest.starts_with('&') {
+            position = match entity(rest) {
+                Some((decoded, length)) => {
+                    blocks.push_str(&decoded, index..index + length);
+                    index + length
+                }
+                None => {
+                    blocks.push_input("&", index);
+                    index + 1
+                }
+            };
+        } else if let Some(comment) = rest.strip_prefix("<!--") {
+            position = comment.find("-->").map_or(html.len(), |end| index + 4 + end + 3);
+        } else if let Some(end) = (rest.starts_with("<!") || rest.starts_with("<?")).then(|| markup_end(rest, b'>')).flatten() {
+            position = index + end + 1;
+        } else if let Some(tag) = parse_tag(rest) {
+            position = index + tag.length;
+            if HIDDEN_ELEMENTS.contains(&tag.name.as_str()) && !tag.closing && !tag.self_closing {
+                position = end_of_element(html, position, &tag.name);
+                continue;
+            }
+            if tag.name == "pre" {
+                pre_depth = if tag.closing { pre_depth.saturating_sub(1) } else { pre_depth + 1 };
+            }
+            if BLOCK_ELEMENTS.contains(&tag.name.as_str()) {
+                blocks.end_block();
+            }
+        } else {
+            blocks.push_input("<", index);
+            position = index + 1;
+        }
+    }
+    push_text(&mut blocks, html, position, html.len(), pre_depth > 0);
+    blocks.end_block();
+    blocks
+}
+
+// line breaks in the source are white space, except in preformatted text
+fn push_text(blocks: &mut Blocks, html: &str, start: usize, end: usize, preformatted: bool) {
+    if preformatted {
+        blocks.push_input(&html[start..end], start);
+        return;
+    }
+    let mut last = start;
+    for (offset, _) in html[start..end].match_indices(['\n', '\r', '\t']) {
+        let index = start + offset;
+        blocks.push_input(&html[last..index], last);
+        blocks.push_str(" ", index..index + 1);
+        last = index + 1;
+    }
+    blocks.push_input(&html[last..end], last);
+}
+
+fn parse_tag(rest: &str) -> Option<Tag> {
+    let closing = rest[1..].starts_with('/');
+    let name_start = if closing { 2 } else { 1 };
+    let name_length = rest[name_start..].find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len() - name_start);
+    if name_length == 0 || !rest[name_start..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    // attribute values may contain `>` and `<`, while an unquoted `<` starts the next tag
+    let mut quote = None;
+    for (index, ch) in rest.char_indices().skip(name_start + name_length) {
+        if index >= MAX_TAG_LENGTH {
+            return None;
+        }
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '<') => return None,
+            (None, '>') => {
+                return Some(Tag {
+                    name: rest[name_start..name_start + name_length].to_ascii_lowercase(),
+                    closing,
+                    self_closing: rest[..index].ends_with('/'),
+                    length: index + 1,
+                });
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// position after the end tag of `name`, or the end of the document
+fn end_of_element(html: &str, start: usize, name: &str) -> usize {
+    for (offset, _) in html[start..].match_indices("</") {
+        let index = start + offset + 2;
+        let candidate = html.get(index..index + name.len());
+        if candidate.is_some_and(|candidate| candidate.eq_ignore_ascii_case(name)) {
+            return html[index..].find('>').map_or(html.len(), |end| index + end + 1);
+        }
+    }
+    html.len()
+}
+
+// index of the `close` that ends the markup at the start of `rest`, looking no further than
+// MAX_TAG_LENGTH bytes and stopping at the next `<`
+pub(crate) fn markup_end(rest: &str, close: u8) -> Option<usize> {
+    rest.bytes()
+        .take(MAX_TAG_LENGTH)
+        .enumerate()
+        .skip(1)
+        .take_while(|&(_, byte)| byte != b'<')
+        .find(|&(_, byte)| byte == close)
+        .map(|(index, _)| index)
+}
+
+// decoded text and length of a character reference at the start of `rest`
+pub(crate) fn entity(rest: &str) -> Option<(String, usize)> {
+    let end = rest.bytes().skip(1).take(MAX_ENTITY_LENGTH + 1).position(|byte| byte == b';')? + 1;
+    let name = &rest[1..end];
+    let decoded = if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        char::from_u32(code).filter(|&ch| ch != '\0').unwrap_or('\u{FFFD}').to_string()
+    } else {
+        ENTITIES.iter().find(|(entity, _)| *entity == name)?.1.to_string()
+    };
+    Some((decoded, end + 1))
+}
@@ -1,4 +1,15 @@
-const ABBREVIATION_MAP_JSON: &str = include_str!("abbreviation_map.json");
+#![forbid(unsafe_code)]
+
+pub mod corpus;
+mod error;
+mod tracked;
+mod html;
+mod subtitle;
+#[cfg(feature = "markdown")]
+mod markdown;
+
+pub use error::Error;
+
 
 #[cfg(feature = "thai")]
 mod model {
@@ -8,24 +19,38 @@ mod model {
 #[cfg(feature = "thai")]
 mod thai_processor {
     use super::model;
+    use super::Error;
+    use burn::module::Module;
+    use burn::record::{FullPrecisionSettings, NamedMpkFileRecorder, Recorder};
     use burn::tensor::{Tensor, TensorData, Int};
     use burn::backend::NdArray;
+    use std::ops::Range;
+    use std::sync::Mutex;
+
+    // weights written next to the generated model code by build.rs
+    const RECORD_PATH: &str = concat!(env!("OUT_DIR"), "/model/thai_segmenter");
 
     const WINDOW_SIZE: usize = 30; 
     const INPUT_LEN: usize = WINDOW_SIZE * 2;
 
     type Backend = NdArray<f32>;
 
+    // burn modules are not Sync, so threads sharing the segmenter evaluate clones of the
+    // model, which share its weights, and return them to `idle` for reuse
     pub struct ThaiSegmenter {
-        model: model::Model<Backend>,
+        model: Mutex<model::Model<Backend>>,
+        idle: Mutex<Vec<model::Model<Backend>>>,
         device: <Backend as burn::tensor::backend::Backend>::Device,
     }
 
     impl ThaiSegmenter {
-        pub fn new() -> Self {
+        pub fn new() -> Result<Self, Error> {
             let device = Default::default();
-            let model = model::Model::default();
-            Self { model, device }
+            let record = NamedMpkFileRecorder::<FullPrecisionSettings>::new()
+                .load(RECORD_PATH.into(), &device)
+                .map_err(|error| Error::Model(error.to_string()))?;
+            let model = model::Model::new(&device).load_record(record);
+            Ok(Self { model: Mutex::new(model), idle: Mutex::new(vec![]), device })
         }
 
         fn encode_char(c: char) -> i32 {
@@ -37,7 +62,7 @@ mod thai_processor {
             }
         }
 
-        pub fn segment(&self, text: &str) -> Vec<String> {
+        pub fn segment(&self, text: &str) -> Result<Vec<Range<usize>>, Error> {
             let chars: Vec<char> = text.chars().collect();
             
             let space_indices: Vec<usize> = chars.iter().enumerate()
@@ -45,7 +70,7 @@ mod thai_processor {
             .collect();
 
             if space_indices.is_empty() {
-                return vec![text.to_string()];
+                return Ok(std::iter::once(0..text.len()).collect());
             }
 
             let num_spaces = space_indices.len();
@@ -56,2252 +81,4658 @@ mod thai_processor {
             let text_len = encoded_text.len();
             for &space_idx in &space_indices {
                 let left_start = space_idx as isize - WINDOW_SIZE as isize;
-                let padding_count = if left_start < 0 { left_start.abs() as usize } else { 0 };
+                let padding_count = if left_start < 0 { left_start.unsigned_abs() } else { 0 };
                 
-                for _ in 0..padding_count {
-                    flattened_input.push(0);
-                }
+                flattened_input.extend(std::iter::repeat_n(0, padding_count));
                 
                 let start_idx = if left_start < 0 { 0 } else { left_start as usize };
-                for i in start_idx..space_idx {
-                    flattened_input.push(encoded_text[i]);
-                }
+                flattened_input.extend_from_slice(&encoded_text[start_idx..space_idx]);
 
                 let right_start = space_idx + 1;
                 let right_end = right_start + WINDOW_SIZE;
                 
                 let copy_end = std::cmp::min(right_end, text_len);
-                for i in right_start..copy_end {
-                    flattened_input.push(encoded_text[i]);
-                }
+                flattened_input.extend_from_slice(&encoded_text[right_start..copy_end]);
                 
-                let remaining = right_end as isize - copy_end as isize;
-                if remaining > 0 {
-                    for _ in 0..remaining {
-                        flattened_input.push(0);
-                    }
-                }
+                flattened_input.extend(std::iter::repeat_n(0, right_end - copy_end));
             }
 
             let input_shape = [num_spaces, INPUT_LEN];
             let input_data = TensorData::new(flattened_input, input_shape);
             let input_tensor: Tensor<Backend, 2, Int> = Tensor::from_data(input_data, &self.device);
 
-            let output = self.model.forward(input_tensor);
+            let idle = self.idle.lock().map_err(|error| Error::Model(error.to_string()))?.pop();
+            let model = match idle {
+                Some(model) => model,
+                None => self.model.lock().map_err(|error| Error::Model(error.to_string()))?.clone(),
+            };
+            let output = model.forward(input_tensor);
+            self.idle.lock().map_err(|error| Error::Model(error.to_string()))?.push(model);
 
-            let output_probs: Vec<f32> = output.into_data().to_vec().unwrap();
+            let output_probs: Vec<f32> = output.into_data().to_vec().map_err(|error| Error::Model(format!("{:?}", error)))?;
 
+            // byte ranges of the pieces between the spaces the model splits at
+            let byte_offsets: Vec<usize> = text.char_indices().map(|(index, _)| index).chain(std::iter::once(text.len())).collect();
             let mut sentences = Vec::new();
             let mut last_offset = 0;
 
             for (i, &space_idx) in space_indices.iter().enumerate() {
                 let prob = output_probs[i];
                 if prob > 0.5 {
-                    let segment: String = chars[last_offset..space_idx].iter().collect();
-                    sentences.push(segment);
+                    sentences.push(byte_offsets[last_offset]..byte_offsets[space_idx]);
                     last_offset = space_idx + 1;
                 }
             }
 
             if last_offset < chars.len() {
-                let segment: String = chars[last_offset..].iter().collect();
-                sentences.push(segment);
+                sentences.push(byte_offsets[last_offset]..text.len());
             }
 
-            sentences
+            Ok(sentences)
         }
     }
 }
 
 pub mod processor {
     use super::*;
-    // use std::fs;
-    use serde_json::Value;
-    use std::collections::HashMap;
-    use fancy_regex::Regex;
+    use crate::tracked::{placeholder, placeholder_index, Blocks, TrackedText};
+    use std::borrow::Cow;
+    use std::fmt;
+    use std::ops::Range;
+    use std::sync::OnceLock;
+    use std::time::Duration;
+    use fancy_regex::{Regex, RegexBuilder};
+    #[cfg(feature = "rayon")]
+    use rayon::prelude::*;
     use regex::Regex as SecondRegex;
 
-    struct LanguageConfig {
-        alphabets: String,
-        have_capital_letter: bool,
-        period: String,
-        question_mark: String,
-        exclamation_mark: String,
-        other_punctuations: Vec<String>,
-    }
-
-    fn process(text: &str, config: LanguageConfig) -> Vec<String> {
-        // step 1 : remove redundant \n, \t, \r and \s+
-        let redundant_space_rule = Regex::new(r"\s+").unwrap();
-        let mut filtered_string = redundant_space_rule.replace_all(&text, " ").to_string();
-        let new_line_rule = Regex::new(r"\n").unwrap();
-        filtered_string = new_line_rule.replace_all(&filtered_string, " ").to_string();
-        let tab_rule = Regex::new(r"\t").unwrap();
-        filtered_string = tab_rule.replace_all(&filtered_string, " ").to_string();
-        let carriage_return_rule = Regex::new(r"\r").unwrap();
-        filtered_string = carriage_return_rule.replace_all(&filtered_string, " ").to_string();
-
-        let all_punctuations = "¿¡、，\u{0021}\u{002E}\u{003F}\u{0589}\u{061F}\u{06D4}\u{0700}\u{0701}\u{0702}\u{07F9}\u{0964}\u{0965}\u{104A}\u{104B}\u{1362}\u{1367}\u{1368}\u{166E}\u{1803}\u{1809}\u{1944}\u{1945}\u{1AA8}\u{1AA9}\u{1AAA}\u{1AAB}\u{1B5A}\u{1B5B}\u{1B5E}\u{1B5F}\u{1C3B}\u{1C3C}\u{1C7E}\u{1C7F}\u{203C}\u{203D}\u{2047}\u{2048}\u{2049}\u{2E2E}\u{3002}\u{A4FF}\u{A60E}\u{A60F}\u{A6F3}\u{A6F7}\u{A876}\u{A877}\u{A8CE}\u{A8CF}\u{A92F}\u{A9C8}\u{A9C9}\u{AA5D}\u{AA5E}\u{AA5F}\u{AAF0}\u{AAF1}\u{ABEB}\u{FE52}\u{FE56}\u{FE57}\u{FF01}\u{FF0E}\u{FF1F}\u{FF61}\u{11047}\u{11048}\u{110BE}\u{110BF}\u{110C0}\u{110C1}\u{11141}\u{11142}\u{11143}\u{111C5}\u{111C6}\u{07F9}\u{07F7}".to_string();
-
-        // step 2 : eliminate non-alphabet
-        let alphabet_regex_pattern = format!(
-            r"[^0-9\u{{10D40}}-\u{{10D8F}}\u{{116C0}}-\u{{116C9}}\u{{07C0}}-\u{{07FF}}\u{{A9D0}}-\u{{A9D9}}\u{{17E0}}-\u{{17E9}}\u{{1040}}-\u{{1049}}\u{{0660}}-\u{{0669}}{}\s\{}\\「\\」\\)\\(\\[\\]\\-_]",
-            config.alphabets.clone(),
-            all_punctuations.clone()
-        );
-        let alphabet_regex = Regex::new(&alphabet_regex_pattern).unwrap();
-        filtered_string = alphabet_regex.replace_all(&filtered_string, "").to_string();
-
-        // step 3 : remove numbered list (ex 1., 2., ...)
-        let numbered_list_regex = Regex::new(r"\d+\.\s*").unwrap();
-        filtered_string = numbered_list_regex.replace_all(&filtered_string, " ").to_string();
-
-        // step 4 : mask abbreviations
-        // let abb_data = fs::read_to_string("src/abbreviation_map.json").expect("Unable to read file");
-        // let json: Value = serde_json::from_str(&abb_data).expect("Unable to parse JSON");
-        // let vec_of_vecs: Vec<Vec<String>> = serde_json::from_value(json).expect("Unable to convert to Vec<Vec<String>>");
-        // let mut abbreviations: HashMap<&str, &str> = HashMap::new();
-        // for pair in vec_of_vecs {
-        //     abbreviations.insert(Box::leak(pair[0].clone().into_boxed_str()), Box::leak(pair[1].clone().into_boxed_str()));
-        // }
-
-        // for (key, value) in abbreviations {
-        //     let escaped_key = key.replace(".", r"\.");
-        //     let key_regex = Regex::new(&format!(r"(?<!\S){}(?!\w)", escaped_key)).unwrap();
-        //     filtered_string = key_regex.replace_all(&filtered_string, value).to_string();
-        // }
-        let json: Value = serde_json::from_str(ABBREVIATION_MAP_JSON).expect("Unable to parse JSON");
-        let vec_of_vecs: Vec<Vec<String>> = serde_json::from_value(json).expect("Unable to convert to Vec<Vec<String>>");
-        let mut abbreviations: HashMap<&str, &str> = HashMap::new();
-        for pair in vec_of_vecs {
-            abbreviations.insert(Box::leak(pair[0].clone().into_boxed_str()), Box::leak(pair[1].clone().into_boxed_str()));
-        }
-
-        // step 5 : number rules
-        let period_before_number_rule = Regex::new(r"\.(?=\d)").unwrap();
-        let number_after_period_before_letter_rule = Regex::new(r"(?<=\d)\.(?=\S)").unwrap();
-        let newline_number_period_space_letter_rule = Regex::new(r"(?<=\r\d)\.(?=(\s\S)|\))").unwrap();
-        let start_line_number_period_rule = Regex::new(r"(?<=^\d)\.(?=(\s\S)|\))").unwrap();
-        let start_line_two_digit_number_period_rule = Regex::new(r"(?<=^\d\d)\.(?=(\s\S)|\))").unwrap();
-        filtered_string = period_before_number_rule.replace_all(&filtered_string, " ").to_string();
-        filtered_string = number_after_period_before_letter_rule.replace_all(&filtered_string, " ").to_string();
-        filtered_string = newline_number_period_space_letter_rule.replace_all(&filtered_string, " ").to_string();
-        filtered_string = start_line_number_period_rule.replace_all(&filtered_string, " ").to_string();
-        filtered_string = start_line_two_digit_number_period_rule.replace_all(&filtered_string, " ").to_string();
-    
-        // step 6 : remove continuous punctuation
-        let continuous_punctuation_regex_pattern = format!(r"([{}]{{2,}})(\s|\z)", all_punctuations.clone());
-        let continuous_punctuation_regex = Regex::new(&continuous_punctuation_regex_pattern).unwrap();
-        filtered_string = continuous_punctuation_regex.replace_all(&filtered_string, " ").to_string();
-
-        // step 7 : remove numbered references
-        let numbered_reference_regex_pattern = format!(
-            r"([^\d\s])(\.|∯)((\[(\d{{1,3}},?\s?-?\s?)?\b\d{{1,3}}\])+|((\d{{1,3}}\s?){{0,3}}\d{{1,3}}))( )([{}])",
-            config.alphabets.clone()
-        );
-        let numbered_reference_regex = Regex::new(&numbered_reference_regex_pattern).unwrap();
-        filtered_string = numbered_reference_regex.replace_all(&filtered_string, " ").to_string();
-
-        // step 8 : mask the website domain
-        let domain_regex = SecondRegex::new(r"\b(?:www\.)?([a-zA-Z0-9-]+\.[a-zA-Z]{2,})(?:\.[a-zA-Z]{2,})?\b").unwrap();
-        let mut masked_string = domain_regex.replace_all(&filtered_string, |caps: &regex::Captures| {
-            let domain = caps.get(0).unwrap().as_str();
-            domain.replace(".", "&^&")
-        }).to_string();
-
-        // step 9 : remove email, geo-location, and file format
-        let email_regex = Regex::new(r"(\w+)(\u{0040})(\w+)(\u{002E})(\w+)").unwrap();
-        let geo_location_rule = Regex::new(r"([a-zA-Z]°)\u{002E}(\s*\d+)").unwrap();
-        let file_format_rule = Regex::new(r"(\s)\u{002E}((jpe?g|png|gif|tiff?|pdf|ps|docx?|xlsx?|svg|bmp|tga|exif|odt|html?|txt|rtf|bat|sxw|xml|zip|exe|msi|blend|wmv|mp[34]|pptx?|flac|rb|cpp|cs|js)\s)").unwrap();
-        masked_string = email_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = geo_location_rule.replace_all(&masked_string, " ").to_string();
-        masked_string = file_format_rule.replace_all(&masked_string, " ").to_string();
-
-        // step 10 : remove continuous extra periods
-        let single_new_line_rule = Regex::new(r"\n").unwrap();
-        let three_space_rule = Regex::new(r"(\s\.){3}\s").unwrap();
-        let other_three_period_rule = Regex::new(r"\.\.\.").unwrap();
-        let three_space_rule_japanese = Regex::new(r"(\s。){3}\s").unwrap();
-        let other_three_period_rule_japanese = Regex::new(r"。。。").unwrap();
-        let three_space_rule_chinese = Regex::new(r"(\s\u{FF0C}){3}\s").unwrap();
-        let other_three_period_rule_chinese = Regex::new(r"\u{FF0C}\u{FF0C}\u{FF0C}").unwrap();
-        masked_string = single_new_line_rule.replace_all(&masked_string, " ").to_string();
-        masked_string = three_space_rule.replace_all(&masked_string, " ").to_string();
-        masked_string = other_three_period_rule.replace_all(&masked_string, " ").to_string();
-        masked_string = three_space_rule_japanese.replace_all(&masked_string, " ").to_string();
-        masked_string = other_three_period_rule_japanese.replace_all(&masked_string, " ").to_string();
-        masked_string = three_space_rule_chinese.replace_all(&masked_string, " ").to_string();
-        masked_string = other_three_period_rule_chinese.replace_all(&masked_string, " ").to_string();
-
-        // step 11 : remove quotations
-        let between_double_quotes_regex = Regex::new(r#""(?>[^"\\]+|\\{2}|\\.)*""#).unwrap();
-        let between_quote_arrow_regex = Regex::new(r"«(?>[^»\\]+|\\{2}|\\.)*»").unwrap();
-        let between_quote_slanted_regex = Regex::new(r"“(?>[^”\\]+|\\{2}|\\.)*”").unwrap();
-        let between_square_brackets_regex = Regex::new(r"\[(?>[^\]\\]+|\\{2}|\\.)*\]").unwrap();
-        let between_parens_regex = Regex::new(r"\((?>[^\(\)\\]+|\\{2}|\\.)*\)").unwrap();
-        let between_japanese_single_bracket_regex = Regex::new(r"「(?>[^」\\]+|\\{2}|\\.)*」").unwrap();
-        let between_japanese_double_bracket_regex = Regex::new(r"『(?>[^』\\]+|\\{2}|\\.)*』").unwrap();
-        let between_single_low_nines_regex = Regex::new(r"‚(?>[^’\\]+|\\{2}|\\.)*’").unwrap();
-        let between_double_low_nines_regex = Regex::new(r"„(?>[^”\\]+|\\{2}|\\.)*”").unwrap();
-        let between_full_width_double_quotes_regex = Regex::new(r"“(?>[^”\\]+|\\{2}|\\.)*”").unwrap();
-        let between_full_width_single_quotes_regex = Regex::new(r"‘(?>[^’\\]+|\\{2}|\\.)*’").unwrap();
-        let word_with_leading_apostrophe_regex_pattern = format!(r"(?<=\s)'(?:[^']|'[{}])*'\S", config.alphabets.clone());
-        let word_with_leading_apostrophe = Regex::new(&word_with_leading_apostrophe_regex_pattern).unwrap();
-        let between_em_dashes_regex = Regex::new(r"\-\-(?>[^\-\-])*\-\-").unwrap();
-        masked_string = between_double_quotes_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_quote_arrow_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_quote_slanted_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_square_brackets_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_parens_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_japanese_single_bracket_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_japanese_double_bracket_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_single_low_nines_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_double_low_nines_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_full_width_double_quotes_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_full_width_single_quotes_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = word_with_leading_apostrophe.replace_all(&masked_string, " ").to_string();
-        masked_string = between_em_dashes_regex.replace_all(&masked_string, " ").to_string();
-
-        // step 12 : remove miscellaneous
-        let three_consecutive_rule_regex_pattern = format!(r"\.\.\.(?=\s+[{}])", config.alphabets.clone());
-        let three_consecutive_rule = Regex::new(&three_consecutive_rule_regex_pattern).unwrap();
-        let four_consecutive_rule_regex_pattern = format!(r"\.\.\.\.(?=\s+[{}])", config.alphabets.clone());
-        let four_consecutive_rule = Regex::new(&four_consecutive_rule_regex_pattern).unwrap();
-        let four_space_rule_regex_pattern = format!(r"(?<=[{}])(\.\s){{3}}\.(\z|$|\n)", config.alphabets.clone());
-        let four_space_rule = Regex::new(&four_space_rule_regex_pattern).unwrap();
-        let between_single_quotes_regex_pattern = format!(r"(?<=\s)'(?:[^']|'[{}])*'", config.alphabets.clone());
-        let between_single_quotes_regex = Regex::new(&between_single_quotes_regex_pattern).unwrap();
-        let between_single_quotes_slanted_regex_pattern = format!(r"(?<=\s)‘(?:[^’]|’[{}])*’", config.alphabets.clone());
-        let between_single_quote_slanted_regex = Regex::new(&between_single_quotes_slanted_regex_pattern).unwrap();
-        let roman_numerals_regex = Regex::new(r"(?<=\S)\b((?=[mdclxvi])m*(c[md]|d?c*)(x[cl]|l?x*)(i[xv]|v?i*))\b(?=\s|$)").unwrap();
-        masked_string = three_consecutive_rule.replace_all(&masked_string, " ").to_string();
-        masked_string = four_consecutive_rule.replace_all(&masked_string, " ").to_string();
-        masked_string = four_space_rule.replace_all(&masked_string, " ").to_string();
-        masked_string = between_single_quotes_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = between_single_quote_slanted_regex.replace_all(&masked_string, " ").to_string();
-        masked_string = roman_numerals_regex.replace_all(&masked_string, " ").to_string();
-
-        // step 13 : remove extra white space
-        let extra_white_space_rule = Regex::new(r"\s{1,}").unwrap();
-        masked_string = extra_white_space_rule.replace_all(&masked_string, " ").to_string();
-
-        // step 14 : mask exclamation words
-        let exclamation_word_masking_map: HashMap<&str, &str> = vec![
-            ("!Xũ", "&ᓴ&Xũ"), ("!Kung", "&ᓴ&Kung"), ("ǃʼOǃKung", "&ᓴ&ʼO&ᓴ&Kung"), ("!Xuun", "&ᓴ&Xuun"), ("!Kung-Ekoka", "&ᓴ&Kung&ᓴ&Ekoka"), ("ǃHu", "&ᓴ&Hu"), ("ǃKhung", "&ᓴ&Khung"), ("ǃKu", "&ᓴ&Ku"), ("ǃung", "&ᓴ&ung"), ("ǃXo", "&ᓴ&Xo"), ("ǃXû", "&ᓴ&Xû"), ("ǃXung", "&ᓴ&Xung"), ("ǃXũ", "&ᓴ&Xũ"), ("!Xun", "&ᓴ&Xun"), ("Yahoo!", "Yahoo&ᓴ&"), ("Y!J", "Y&ᓴ&J"), ("Yum!", "Yum&ᓴ&"),
-        ].into_iter().collect();
-        for (key, value) in exclamation_word_masking_map {
-            let key_regex = Regex::new(&format!(r"\b{}\b", key)).unwrap();
-            masked_string = key_regex.replace_all(&masked_string, value).to_string();
-        }
-
-        // step 15 : apply non boundary exclamation mark rules
-        let exclamation_mark_before_comma_mid_sentence_regex_pattern = format!(
-            r"({}|!)(?=\,\s[{}])",
-            config.exclamation_mark.clone(),
-            config.alphabets.clone()
-        );
-        let exclamation_mark_before_comma_mid_sentence_regex = Regex::new(&exclamation_mark_before_comma_mid_sentence_regex_pattern).unwrap();
-        masked_string = exclamation_mark_before_comma_mid_sentence_regex.replace_all(&masked_string, "&ᓴ&").to_string();
-
-        // step 16 : mask question mark in quotation
-        let question_mark_in_quotation_regex_pattern = format!(
-            r#"\{}(?=(\'|\"|[{}]))"#,
-            config.question_mark.clone(),
-            config.alphabets.clone(),
-        );
-        let question_mark_in_quotation_regex = Regex::new(&question_mark_in_quotation_regex_pattern).unwrap();
-        masked_string = question_mark_in_quotation_regex.replace_all(&masked_string, "&ᓷ&").to_string();
-
-        // step 17 : sentence segmentation and unmask
-        let mut sentence_end_punctuation = vec![
-            config.period.as_str(), config.question_mark.as_str(), config.exclamation_mark.as_str(),
-        ];
-        for punctuation in &config.other_punctuations {
-            sentence_end_punctuation.push(punctuation.as_str());
-        }
-        let mut segmented_sentence_candidates: Vec<String> = vec![];
-        let mut sentence = String::new();
-
-        for ch in masked_string.chars() {
-            if sentence_end_punctuation.contains(&ch.to_string().as_str()) {
-                let mut full_sentence_candidate = sentence.trim().to_string();
-                full_sentence_candidate.push(ch);
-                full_sentence_candidate = full_sentence_candidate.replace("&ᓷ&", config.question_mark.clone().as_str());
-                full_sentence_candidate = full_sentence_candidate.replace("&ᓴ&", config.exclamation_mark.clone().as_str());
-                full_sentence_candidate = full_sentence_candidate.replace("&^&", ".");
-                full_sentence_candidate = full_sentence_candidate.trim().to_string();
-                let extra_white_space_rule = Regex::new(r"\s{1,}").unwrap();
-                full_sentence_candidate = extra_white_space_rule.replace_all(&full_sentence_candidate, " ").to_string();
-                if full_sentence_candidate.len() > 2 {
-                    segmented_sentence_candidates.push(full_sentence_candidate);
-                    sentence = String::new();
+    // fancy-regex counts backtracking steps over a whole scan, so its default limit of
+    // one million is already reached by the lookaround rules on a 1 MB document.
+    const BACKTRACK_LIMIT: usize = 1_000_000_000;
+
+    const ALL_PUNCTUATIONS: &str = "¿¡、，：\u{0021}\u{002E}\u{003F}\u{0589}\u{061F}\u{06D4}\u{0700}\u{0701}\u{0702}\u{07F9}\u{0964}\u{0965}\u{104A}\u{104B}\u{1362}\u{1367}\u{1368}\u{166E}\u{1803}\u{1809}\u{1944}\u{1945}\u{1AA8}\u{1AA9}\u{1AAA}\u{1AAB}\u{1B5A}\u{1B5B}\u{1B5E}\u{1B5F}\u{1C3B}\u{1C3C}\u{1C7E}\u{1C7F}\u{203C}\u{203D}\u{2047}\u{2048}\u{2049}\u{2E2E}\u{3002}\u{A4FF}\u{A60E}\u{A60F}\u{A6F3}\u{A6F7}\u{A876}\u{A877}\u{A8CE}\u{A8CF}\u{A92F}\u{A9C8}\u{A9C9}\u{AA5D}\u{AA5E}\u{AA5F}\u{AAF0}\u{AAF1}\u{ABEB}\u{FE52}\u{FE56}\u{FE57}\u{FF01}\u{FF0E}\u{FF1F}\u{FF61}\u{11047}\u{11048}\u{110BE}\u{110BF}\u{110C0}\u{110C1}\u{11141}\u{11142}\u{11143}\u{111C5}\u{111C6}\u{07F9}\u{07F7}";
+    // the marks of ALL_PUNCTUATIONS that never end a sentence
+    const NON_TERMINATORS: &str = "¿¡、，：";
+    // quoted speech holding more sentences than this is split at its own terminators
+    const MAX_QUOTED_SENTENCES: usize = 2;
+    // the final consonants of Hangul syllables, as compatibility jamo in syllable order
+    const HANGUL_FINALS: [char; 27] = ['ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ'];
+
+    /// Alphabet and sentence punctuation of a language. The built-in languages are in [`config`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct LanguageConfig {
+        /// Body of a regex character class matching the letters of the language, e.g.
+        /// `\p{Latin}\p{M}` or `a-zA-Z`, see [`config::scripts`].
+        pub alphabets: String,
+        /// Whether the script has letter case. A sentence starting in lowercase is then merged into the previous one.
+        pub have_capital_letter: bool,
+        /// Full stop, by default also appended to a trailing fragment that has no terminator.
+        pub period: String,
+        pub question_mark: String,
+        pub exclamation_mark: String,
+        /// Further characters that end a sentence.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub other_punctuations: Vec<String>,
+        /// Words that may follow an ordinal number written with a period.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub ordinals: Ordinals,
+        /// Whether questions and exclamations open with `¿` and `¡`, as in Spanish. A `?` or `!`
+        /// closing them then ends the sentence only when the next sentence follows.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub inverted_marks: bool,
+        /// Whether `;` asks a question, as in Greek. It then ends the sentence after a letter of
+        /// the language and before the start of the next sentence, and stays a semicolon
+        /// elsewhere.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub semicolon_questions: bool,
+        /// Quotation marks that hold quoted speech and stay in the sentence.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub quotes: Quotes,
+        /// Sentence-final endings, such as Korean `-니다` and `-어요`, that end a sentence before
+        /// white space even without punctuation. An ending starting with a final consonant, such
+        /// as `ㅆ다`, matches a syllable ending in that consonant, such as `왔다`.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub final_endings: Vec<String>,
+        /// Abbreviations, such as `Dr.`, whose period does not end a sentence unless it ends the
+        /// text.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub abbreviations: Vec<String>,
+        /// Whether a narrow no-break space U+202F before a letter joins a suffix to its word, as
+        /// in traditional Mongolian `ᠨᠣᠮ\u{202F}ᠢ`, and is kept instead of becoming a space.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub suffix_spaces: bool,
+    }
+
+    /// Quoted speech kept in the sentence, as in Japanese 「行きます。」と言った. Terminators
+    /// within quotation marks do not end the sentence, and the closing marks after a terminator
+    /// belong to the sentence it ends. The text after them continues that sentence when it
+    /// starts with one of `continuations`, and starts a new one otherwise. Empty for languages
+    /// whose quotations are removed by [`Cleaning::quotations`].
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub struct Quotes {
+        /// Opening and closing mark of each kind of quotation, e.g. `「」`.
+        pub pairs: Vec<String>,
+        /// Words and punctuation that continue a sentence after quoted speech, such as the
+        /// Japanese particle `と` or the Chinese `他说`.
+        pub continuations: Vec<String>,
+    }
+
+    /// Words that follow ordinal numbers written with a period, as in German "am 3. Oktober" and
+    /// "der 2. Weltkrieg". A number of at most four digits whose period is followed by one of the
+    /// words, in any case, does not end the sentence and is not taken for a numbered list.
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub struct Ordinals {
+        /// Month names in the forms used in dates, e.g. the genitive `října` in Czech.
+        pub months: Vec<String>,
+        /// Nouns that are often counted, such as `Weltkrieg`, `Jahrhundert` or `Platz`.
+        pub nouns: Vec<String>,
+        /// Articles after which a number with a period is an ordinal when a word follows, as in
+        /// German "die 2. Staffel".
+        pub articles: Vec<String>,
+    }
+
+    enum Rule {
+        Replace(Regex, String),
+        MaskDomains(SecondRegex),
+    }
+
+    /// A sentence found by [`Segmenter::segment`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Sentence {
+        /// Cleaned sentence text, as returned by the `processor::*` functions.
+        pub text: String,
+        /// Byte range of the input the sentence was produced from. Text removed by the
+        /// cleaning rules inside the range is not part of `text`.
+        pub span: Range<usize>,
+        /// Punctuation that ended the sentence, normally the period, question mark, exclamation
+        /// mark or one of the other punctuations of the language. `None` for the pieces the
+        /// Thai model splits off before the end of a rule-based sentence.
+        pub terminator: Option<char>,
+        /// Whether `terminator` is not in the input but was appended to a trailing fragment.
+        pub synthetic_terminator: bool,
+        /// Whether sentences starting in lowercase were merged into this one.
+        pub merged: bool,
+        /// Policy applied when the sentence ends with the unterminated fragment at the end of
+        /// the text, `None` for sentences closed by punctuation in the input.
+        pub trailing_fragment: Option<TrailingFragment>,
+    }
+
+    impl fmt::Display for Sentence {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&self.text)
+        }
+    }
+
+    /// Sentences of one paragraph, see [`Segmenter::segment_paragraphs`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Paragraph {
+        /// Byte range of the paragraph in the input, without the surrounding white space.
+        pub span: Range<usize>,
+        pub sentences: Vec<Sentence>,
+    }
+
+    /// A sentence of a subtitle file with its timing, see [`Segmenter::segment_subtitles`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct TimedSentence {
+        pub sentence: Sentence,
+        /// Time at which the sentence starts, interpolated by characters within its first cue.
+        pub start: Duration,
+        /// Time at which the sentence ends, interpolated by characters within its last cue.
+        pub end: Duration,
+    }
+
+    /// What [`Segmenter::segment`] does with text after the last sentence terminator.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+    pub enum TrailingFragment {
+        /// Append the period of the language, as the `processor::*` functions do.
+        #[default]
+        AppendPeriod,
+        /// Return the fragment without a terminator, e.g. for headings and list items.
+        Keep,
+        /// Leave the fragment out of the result.
+        Drop,
+    }
+
+    /// Whether [`Segmenter::segment`] treats line breaks as sentence boundaries.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+    pub enum Newlines {
+        /// Line breaks are white space, as in the `processor::*` functions.
+        #[default]
+        Ignore,
+        /// Every line break ends a sentence, e.g. for lyrics, poems and chat logs.
+        Boundary,
+        /// A line break ends a sentence when the next line starts with a capital letter or a
+        /// list marker such as `-`, `*`, `•` or `1.`.
+        Heuristic,
+    }
+
+    // a sentence whose text still maps back to the input
+    struct Candidate {
+        text: TrackedText,
+        terminator: Option<char>,
+        merged: bool,
+        trailing_fragment: Option<TrailingFragment>,
+    }
+
+    impl Candidate {
+        fn new(text: TrackedText, terminator: Option<char>, trailing_fragment: Option<TrailingFragment>) -> Self {
+            Candidate { text, terminator, merged: false, trailing_fragment }
+        }
+
+        fn into_sentence(self) -> Sentence {
+            Sentence {
+                span: self.text.span(),
+                text: self.text.as_str().to_string(),
+                terminator: self.terminator,
+                synthetic_terminator: self.trailing_fragment == Some(TrailingFragment::AppendPeriod),
+                merged: self.merged,
+                trailing_fragment: self.trailing_fragment,
+            }
+        }
+    }
+
+    /// Cleaning steps of the rules that can be switched off. All of them are on by default.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub struct Cleaning {
+        /// Remove characters that are not letters of the language, digits or punctuation.
+        pub non_alphabet: bool,
+        /// Remove list numbers such as `1.` and `2.` at the start of a line or after the end of a
+        /// sentence.
+        pub numbered_lists: bool,
+        /// Remove numbered references such as `[12]` after the end of a sentence.
+        pub references: bool,
+        /// Remove quotations and bracketed or parenthesised text.
+        pub quotations: bool,
+        /// Symbols that `non_alphabet` keeps. None by default.
+        pub symbols: Symbols,
+    }
+
+    impl Default for Cleaning {
+        fn default() -> Self {
+            Cleaning { non_alphabet: true, numbered_lists: true, references: true, quotations: true, symbols: Symbols::default() }
+        }
+    }
+
+    /// Categories of symbols kept by [`Cleaning::non_alphabet`], which otherwise removes them.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub struct Symbols {
+        /// Currency signs such as `$`, `€` and `₹`.
+        pub currency: bool,
+        /// Mathematical operators such as `+`, `=`, `<`, `±` and `×`, and `*`, `/` and `^`.
+        pub math: bool,
+        /// Percent, per mille and degree signs, e.g. in `50%` and `20°C`.
+        pub percent: bool,
+        /// `#` and `@` of hashtags and mentions.
+        pub social: bool,
+        /// Emoji, including skin tones, flags and sequences joined by U+200D. Emoji right after
+        /// the end of a sentence belong to that sentence.
+        pub emoji: bool,
+    }
+
+    // URLs, emails, IP addresses, file paths and numbers with separators, such as decimals,
+    // versions, dates and times, which the rules would otherwise take apart. A
+    // path must follow white space or an opening bracket or quote, and URLs are made of the
+    // characters RFC 3986 allows plus letters, so they end at CJK punctuation. Numbers may be
+    // followed by a unit or counter, as in `3.5km` and Korean `3.5시간`.
+    const TOKEN_PATTERN: &str = concat!(
+        r#"(?:^|[\s(\[{<"'«“‘])(?P<path>(?:~|\.{1,2})/[\w.\-/]*[\w\-]|/[\w.\-]+(?:/[\w.\-]+)+|[A-Za-z]:\\[^\s]*)"#,
+        r"|(?P<token>(?:https?|ftp)://[\w\-.~:/?#\[\]@!$&'()*+,;=%]+",
+        r"|\bwww\.[\w\-.~:/?#\[\]@!$&'()*+,;=%]+",
+        r"|[\w.+\-]+@[\w\-]+(?:\.[\w\-]+)+",
+        r"|\b(?:\d{1,3}\.){3}\d{1,3}(?::\d{1,5})?\b",
+        r"|\b(?:[0-9A-Fa-f]{1,4}:){7}[0-9A-Fa-f]{1,4}\b",
+        r"|\b[vV]?\d+(?:[.,:/]\d+)+",
+        r"|\b[A-Za-z0-9\-]+(?:\.[A-Za-z0-9\-]+)*\.[A-Za-z]{2,}/[\w\-.~:/?#\[\]@!$&'()*+,;=%]*)",
+    );
+
+    const EMOJI: &str = r"\p{Extended_Pictographic}\p{Emoji_Modifier}\p{Regional_Indicator}\u{200D}\u{FE0F}\u{20E3}\u{E0020}-\u{E007F}";
+
+    impl Symbols {
+        pub fn all() -> Self {
+            Symbols { currency: true, math: true, percent: true, social: true, emoji: true }
+        }
+
+        // body of a character class matching the kept symbols
+        fn class(&self) -> String {
+            let categories = [
+                (self.currency, r"\p{Sc}"),
+                (self.math, r"\p{Sm}\*/\^"),
+                (self.percent, "%‰‱٪°℃℉"),
+                (self.social, "#@"),
+                (self.emoji, EMOJI),
+            ];
+            categories.iter().filter(|(kept, _)| *kept).map(|(_, class)| *class).collect()
+        }
+    }
+
+    /// Compiled segmentation rules of one language.
+    ///
+    /// Compiling the rules is most of the cost of segmenting a short text, so build a
+    /// segmenter once and reuse it when segmenting many texts.
+    pub struct Segmenter {
+        config: LanguageConfig,
+        rules: Vec<Rule>,
+        extra_white_space_rule: Regex,
+        blank_line_rule: SecondRegex,
+        sentence_end_punctuation: Vec<char>,
+        quote_pairs: Vec<(char, char)>,
+        period: char,
+        trailing_fragment: TrailingFragment,
+        newlines: Newlines,
+        line_start_rule: SecondRegex,
+        trailing_emoji_rule: Option<SecondRegex>,
+        token_rule: SecondRegex,
+        #[cfg(feature = "thai")]
+        thai_model: Option<thai_processor::ThaiSegmenter>,
+    }
+
+    impl Segmenter {
+        pub fn new(config: LanguageConfig) -> Result<Self, Error> {
+            Segmenter::with_cleaning(config, Cleaning::default())
+        }
+
+        /// Like [`Segmenter::new`], leaving out the cleaning steps switched off in `cleaning`.
+        pub fn with_cleaning(config: LanguageConfig, cleaning: Cleaning) -> Result<Self, Error> {
+            check_alphabets(&config.alphabets)?;
+            let period = single_char(&config.period)?;
+            let mut sentence_end_punctuation = vec![period, single_char(&config.question_mark)?, single_char(&config.exclamation_mark)?];
+            for punctuation in &config.other_punctuations {
+                sentence_end_punctuation.push(single_char(punctuation)?);
+            }
+            if config.semicolon_questions {
+                sentence_end_punctuation.push(';');
+            }
+            let quote_pairs = config.quotes.pairs.iter().map(|pair| char_pair(pair)).collect::<Result<Vec<_>, _>>()?;
+            let alphabets = config.alphabets.as_str();
+            // the narrow no-break space that joins a suffix to its word, as in ᠨᠣᠮ\u{202F}ᠢ, stays
+            let (white_space, line_white_space) = if config.suffix_spaces {
+                (
+                    format!(r"(?:[^\S\u{{202F}}]|\u{{202F}}(?![{}--\s]))+", alphabets),
+                    format!(r"(?:[^\S\n\u{{202F}}]|\u{{202F}}(?![{}--\s]))+", alphabets),
+                )
+            } else {
+                (r"\s+".to_string(), r"[^\S\n]+".to_string())
+            };
+            let mut rules = vec![
+                // step 1 : remove redundant \n, \t, \r and \s+, keeping a single line break
+                // between lines for step 3
+                substitution(r"\s*\n\s*", "\n")?,
+                substitution(&line_white_space, " ")?,
+                substitution(r"\t", " ")?,
+                substitution(r"\r", " ")?,
+            ];
+
+            // step 2 : eliminate non-alphabet, keeping the terminators of the language such as
+            // the Greek question mark `;`, and the decimal digits of every script (`\p{Nd}`), so
+            // that e.g. Devanagari or full-width numbers survive in any language
+            let terminators: String = sentence_end_punctuation
+                .iter()
+                .chain(quote_pairs.iter().flat_map(|(open, close)| [open, close]))
+                .map(|ch| fancy_regex::escape(&ch.to_string()).into_owned())
+                .collect();
+            let alphabet_regex_pattern = format!(
+                r"[^0-9\p{{Nd}}\u{{10D40}}-\u{{10D8F}}\u{{116C0}}-\u{{116C9}}\u{{07C0}}-\u{{07FF}}\u{{A9D0}}-\u{{A9D9}}\u{{17E0}}-\u{{17E9}}\u{{1040}}-\u{{1049}}\u{{0660}}-\u{{0669}}\u{{F0000}}-\u{{FFFFD}}{}{}{}\s\{}\\「\\」\\)\\(\[\]\\^_]",
+                alphabets,
+                cleaning.symbols.class(),
+                terminators,
+                ALL_PUNCTUATIONS
+            );
+            if cleaning.non_alphabet {
+                rules.push(substitution(&alphabet_regex_pattern, "")?);
+            }
+
+            // keep the period of an ordinal number away from step 3 and from the boundaries
+            let ordinal_words: Vec<String> = config
+                .ordinals
+                .months
+                .iter()
+                .chain(&config.ordinals.nouns)
+                .map(|word| fancy_regex::escape(word).into_owned())
+                .collect();
+            if !ordinal_words.is_empty() {
+                let ordinal_pattern = format!(
+                    r"(?:(?<=\b\d)|(?<=\b\d{{2}})|(?<=\b\d{{3}})|(?<=\b\d{{4}}))\.(?=\s+(?i:{})\b)",
+                    ordinal_words.join("|")
+                );
+                rules.push(substitution(&ordinal_pattern, "&^&")?);
+            }
+            let ordinal_articles: Vec<String> = config
+                .ordinals
+                .articles
+                .iter()
+                .flat_map(|article| {
+                    let article = fancy_regex::escape(article).into_owned();
+                    (1..=4).map(move |digits| format!(r"(?<=\b(?i:{})\s\d{{{}}})", article, digits))
+                })
+                .collect();
+            // after an article only when a lowercase word or one of the words follows, since a
+            // capitalised word may start the next sentence as in "Das ist die 3. Sie ist gut."
+            if !ordinal_articles.is_empty() {
+                let next_word = if ordinal_words.is_empty() {
+                    r"\p{Ll}".to_string()
                 } else {
-                    sentence = String::new();
+                    format!(r"\p{{Ll}}|(?i:{})\b", ordinal_words.join("|"))
+                };
+                let ordinal_pattern = format!(r"(?:{})\.(?=\s+(?:{}))", ordinal_articles.join("|"), next_word);
+                rules.push(substitution(&ordinal_pattern, "&^&")?);
+            }
+
+            // step 3 : remove numbered list (ex 1., 2., ...) at the start of a line or after the
+            // end of a sentence, of at most three digits so that a year ending a sentence stays
+            if cleaning.numbered_lists {
+                let sentence_ends: String = ALL_PUNCTUATIONS
+                    .chars()
+                    .filter(|ch| !NON_TERMINATORS.contains(*ch))
+                    .chain(sentence_end_punctuation.iter().copied())
+                    .map(|ch| fancy_regex::escape(&ch.to_string()).into_owned())
+                    .collect();
+                rules.push(substitution(&format!(r"(?m)(?:^|(?<=[{0}])|(?<=[{0}] ))\d{{1,3}}\.\s*(?=\S)", sentence_ends), " ")?);
+            }
+            rules.push(substitution(r"\n", " ")?);
+
+            // step 4 : keep the periods of the abbreviations of the language, except at the end of
+            // the text
+            let abbreviations: Vec<String> = config
+                .abbreviations
+                .iter()
+                .map(|abbreviation| {
+                    let abbreviation = fancy_regex::escape(abbreviation.trim_end_matches('.')).into_owned();
+                    format!(r"(?<=\b{})", abbreviation)
+                })
+                .collect();
+            if !abbreviations.is_empty() {
+                let abbreviation_pattern = format!(r"(?:{})\.(?!\s*\z)", abbreviations.join("|"));
+                rules.push(substitution(&abbreviation_pattern, "&^&")?);
+            }
+
+            // step 5 : number rules
+            rules.push(substitution(r"\.(?=\d)", " ")?);
+            rules.push(substitution(r"(?<=\d)\.(?=\S)", " ")?);
+            rules.push(substitution(r"(?<=\r\d)\.(?=(\s\S)|\))", " ")?);
+            rules.push(substitution(r"(?<=^\d)\.(?=(\s\S)|\))", " ")?);
+            rules.push(substitution(r"(?<=^\d\d)\.(?=(\s\S)|\))", " ")?);
+
+            // step 6 : remove continuous punctuation
+            rules.push(substitution(&format!(r"([{}]{{2,}})(\s|\z)", ALL_PUNCTUATIONS), " ")?);
+
+            // step 7 : remove numbered references
+            let numbered_reference_regex_pattern = format!(
+                r"([^\d\s])(\.|∯)((\[(\d{{1,3}},?\s?-?\s?)?\b\d{{1,3}}\])+|((\d{{1,3}}\s?){{0,3}}\d{{1,3}}))( )([{}])",
+                alphabets
+            );
+            if cleaning.references {
+                rules.push(substitution(&numbered_reference_regex_pattern, " ")?);
+            }
+
+            // step 8 : mask the website domain
+            rules.push(Rule::MaskDomains(SecondRegex::new(r"\b(?:www\.)?([a-zA-Z0-9-]+\.[a-zA-Z]{2,})(?:\.[a-zA-Z]{2,})?\b").unwrap()));
+
+            // step 9 : remove email, geo-location, and file format
+            rules.push(substitution(r"(\w+)(\u{0040})(\w+)(\u{002E})(\w+)", " ")?);
+            rules.push(substitution(r"([a-zA-Z]°)\u{002E}(\s*\d+)", " ")?);
+            rules.push(substitution(r"(\s)\u{002E}((jpe?g|png|gif|tiff?|pdf|ps|docx?|xlsx?|svg|bmp|tga|exif|odt|html?|txt|rtf|bat|sxw|xml|zip|exe|msi|blend|wmv|mp[34]|pptx?|flac|rb|cpp|cs|js)\s)", " ")?);
+
+            // step 10 : remove continuous extra periods
+            rules.push(substitution(r"\n", " ")?);
+            rules.push(substitution(r"(\s\.){3}\s", " ")?);
+            rules.push(substitution(r"\.\.\.", " ")?);
+            rules.push(substitution(r"(\s。){3}\s", " ")?);
+            rules.push(substitution(r"。。。", " ")?);
+            rules.push(substitution(r"(\s\u{FF0C}){3}\s", " ")?);
+            rules.push(substitution(r"\u{FF0C}\u{FF0C}\u{FF0C}", " ")?);
+
+            // step 11 : remove quotations, except for the quoted speech of the language and groups
+            // holding nothing but a protected token, e.g. a bracketed URL
+            let removed = |open: char, close: char| !quote_pairs.contains(&(open, close));
+            let group = |open: &str, body: &str, close: &str| {
+                format!(r"{open}(?!\s*[\u{{F0000}}-\u{{FFFFD}}]\s*{close})(?>{body}+|\\{{2}}|\\.)*{close}")
+            };
+            if cleaning.quotations {
+                rules.push(substitution(&group("\"", r#"[^"\\]"#, "\""), " ")?);
+                rules.push(substitution(&group("«", r"[^»\\]", "»"), " ")?);
+                if removed('“', '”') {
+                    rules.push(substitution(&group("“", r"[^”\\]", "”"), " ")?);
+                }
+                rules.push(substitution(&group(r"\[", r"[^\]\\]", r"\]"), " ")?);
+                rules.push(substitution(&group(r"\(", r"[^\(\)\\]", r"\)"), " ")?);
+                if removed('「', '」') {
+                    rules.push(substitution(&group("「", r"[^」\\]", "」"), " ")?);
+                }
+                if removed('『', '』') {
+                    rules.push(substitution(&group("『", r"[^』\\]", "』"), " ")?);
+                }
+                rules.push(substitution(&group("‚", r"[^’\\]", "’"), " ")?);
+                rules.push(substitution(&group("„", r"[^”\\]", "”"), " ")?);
+                if removed('“', '”') {
+                    rules.push(substitution(&group("“", r"[^”\\]", "”"), " ")?);
+                }
+                rules.push(substitution(&group("‘", r"[^’\\]", "’"), " ")?);
+                rules.push(substitution(&format!(r"(?<=\s)'(?:[^']|'[{}])*'\S", alphabets), " ")?);
+                rules.push(substitution(r"\-\-(?>[^\-\-])*\-\-", " ")?);
+            }
+
+            // step 12 : remove miscellaneous
+            rules.push(substitution(&format!(r"\.\.\.(?=\s+[{}])", alphabets), " ")?);
+            rules.push(substitution(&format!(r"\.\.\.\.(?=\s+[{}])", alphabets), " ")?);
+            rules.push(substitution(&format!(r"(?<=[{}])(\.\s){{3}}\.(\z|$|\n)", alphabets), " ")?);
+            if cleaning.quotations {
+                rules.push(substitution(&format!(r"(?<=\s)'(?:[^']|'[{}])*'", alphabets), " ")?);
+                rules.push(substitution(&format!(r"(?<=\s)‘(?:[^’]|’[{}])*’", alphabets), " ")?);
+            }
+            rules.push(substitution(r"(?<=\S)\b((?=[mdclxvi])m*(c[md]|d?c*)(x[cl]|l?x*)(i[xv]|v?i*))\b(?=\s|$)", " ")?);
+
+            // step 13 : remove extra white space
+            rules.push(substitution(&white_space, " ")?);
+
+            // step 14 : mask exclamation words
+            let exclamation_word_masking_map = [
+                ("!Xũ", "&ᓴ&Xũ"), ("!Kung", "&ᓴ&Kung"), ("ǃʼOǃKung", "&ᓴ&ʼO&ᓴ&Kung"), ("!Xuun", "&ᓴ&Xuun"), ("!Kung-Ekoka", "&ᓴ&Kung&ᓴ&Ekoka"), ("ǃHu", "&ᓴ&Hu"), ("ǃKhung", "&ᓴ&Khung"), ("ǃKu", "&ᓴ&Ku"), ("ǃung", "&ᓴ&ung"), ("ǃXo", "&ᓴ&Xo"), ("ǃXû", "&ᓴ&Xû"), ("ǃXung", "&ᓴ&Xung"), ("ǃXũ", "&ᓴ&Xũ"), ("!Xun", "&ᓴ&Xun"), ("Yahoo!", "Yahoo&ᓴ&"), ("Y!J", "Y&ᓴ&J"), ("Yum!", "Yum&ᓴ&"),
+            ];
+            for (key, value) in exclamation_word_masking_map {
+                rules.push(substitution(&format!(r"\b{}\b", key), value)?);
+            }
+
+            // step 15 : apply non boundary exclamation mark rules
+            let exclamation_mark_before_comma_mid_sentence_regex_pattern = format!(
+                r"({}|!)(?=\,\s[{}])",
+                config.exclamation_mark,
+                alphabets
+            );
+            rules.push(substitution(&exclamation_mark_before_comma_mid_sentence_regex_pattern, "&ᓴ&")?);
+
+            // step 16 : mask question mark in quotation, unless it ends quoted speech kept by the
+            // language, whose closing mark may belong to the alphabet such as `」` to Han
+            let closing_quotes: String = quote_pairs.iter().map(|(_, close)| fancy_regex::escape(&close.to_string()).into_owned()).collect();
+            let question_mark_in_quotation_regex_pattern = format!(
+                r#"\{}(?=(\'|\"|[{}])){}"#,
+                config.question_mark,
+                alphabets,
+                if closing_quotes.is_empty() { String::new() } else { format!("(?![{}])", closing_quotes) },
+            );
+            rules.push(substitution(&question_mark_in_quotation_regex_pattern, "&ᓷ&")?);
+
+            // a semicolon used as question mark, as in Greek, ends a sentence only after a letter
+            // of the language's own script and before the start of the next sentence, so that
+            // the semicolons of Latin text within stay
+            if config.semicolon_questions {
+                let semicolon_after_other_script_regex_pattern = format!(
+                    r#"(?<![[{}--\p{{scx=Latin}}]»”’"')]);"#,
+                    alphabets
+                );
+                rules.push(substitution(&semicolon_after_other_script_regex_pattern, "&ᓵ&")?);
+                rules.push(substitution(r#";(?!\s*(?:\z|[\p{Lu}\d«“"'(]))"#, "&ᓵ&")?);
+            }
+
+            Ok(Segmenter {
+                rules,
+                extra_white_space_rule: rule(&white_space)?,
+                blank_line_rule: SecondRegex::new(r"\n[^\S\n]*\n\s*").unwrap(),
+                sentence_end_punctuation,
+                quote_pairs,
+                period,
+                trailing_fragment: TrailingFragment::default(),
+                newlines: Newlines::default(),
+                line_start_rule: SecondRegex::new(r"^\s*(\p{Lu}|[-*+•‣◦–—]\s|\d{1,3}[.)]\s)").unwrap(),
+                token_rule: SecondRegex::new(TOKEN_PATTERN).unwrap(),
+                trailing_emoji_rule: cleaning.symbols.emoji.then(|| SecondRegex::new(&format!(r"^(?:\s*[{}])+", EMOJI)).unwrap()),
+                config,
+                #[cfg(feature = "thai")]
+                thai_model: None,
+            })
+        }
+
+        /// Segmenter for Thai, which splits the rule-based sentences further with a small CNN.
+        #[cfg(feature = "thai")]
+        pub fn thai() -> Result<Self, Error> {
+            Segmenter::new(config::thai())?.with_thai_model()
+        }
+
+        /// Splits the rule-based sentences further with the Thai model.
+        #[cfg(feature = "thai")]
+        pub fn with_thai_model(mut self) -> Result<Self, Error> {
+            self.thai_model = Some(thai_processor::ThaiSegmenter::new()?);
+            Ok(self)
+        }
+
+        /// Sets what happens to text after the last sentence terminator. The default appends
+        /// the period of the language.
+        pub fn with_trailing_fragment(mut self, trailing_fragment: TrailingFragment) -> Self {
+            self.trailing_fragment = trailing_fragment;
+            self
+        }
+
+        /// Sets whether line breaks end sentences. By default they are white space.
+        pub fn with_newlines(mut self, newlines: Newlines) -> Self {
+            self.newlines = newlines;
+            self
+        }
+
+        pub fn segment(&self, text: &str) -> Result<Vec<Sentence>, Error> {
+            self.segment_tracked(TrackedText::new(text), &[])
+        }
+
+        /// Like [`Segmenter::segment`], but a sentence never crosses an empty line. Paragraphs
+        /// without any sentence are left out.
+        pub fn segment_paragraphs(&self, text: &str) -> Result<Vec<Paragraph>, Error> {
+            let tracked = TrackedText::new(text);
+            let mut paragraphs = vec![];
+            let mut start = 0;
+            let separators = self.blank_line_rule.find_iter(text).map(|found| found.range());
+            for separator in separators.chain(std::iter::once(text.len()..text.len())) {
+                let paragraph = tracked.slice(start..separator.start).trim();
+                let span = paragraph.span();
+                start = separator.end;
+                let sentences = self.segment_tracked(paragraph, &[])?;
+                if !sentences.is_empty() {
+                    paragraphs.push(Paragraph { span, sentences });
+                }
+            }
+            Ok(paragraphs)
+        }
+
+        // swaps the placeholders of protected tokens back into the sentences; `tokens` holds the
+        // tokens shared by all blocks of a document, and those found in `text` follow them
+        fn segment_tracked(&self, mut text: TrackedText, tokens: &[String]) -> Result<Vec<Sentence>, Error> {
+            // placeholders without a token come from the input, and step 2 used to remove them
+            let stray = text.as_str().char_indices()
+                .filter(|&(_, ch)| placeholder_index(ch).is_some_and(|index| index >= tokens.len()))
+                .map(|(index, ch)| (index..index + ch.len_utf8(), Cow::Borrowed("")))
+                .collect();
+            text.replace(stray);
+            let found = self.protect_tokens(&mut text, tokens.len());
+            let mut sentences = self.segment_lines(text)?;
+            if !tokens.is_empty() || !found.is_empty() {
+                for sentence in &mut sentences {
+                    let mut restored = String::with_capacity(sentence.text.len());
+                    for ch in sentence.text.chars() {
+                        match placeholder_index(ch) {
+                            Some(index) => restored.push_str(tokens.get(index).unwrap_or_else(|| &found[index - tokens.len()])),
+                            None => restored.push(ch),
+                        }
+                    }
+                    sentence.text = restored;
+                }
+            }
+            Ok(sentences)
+        }
+
+        // replaces URLs, emails, file paths and IP addresses by placeholders numbered from
+        // `first`, leaving out the punctuation that follows them so it still ends the sentence,
+        // and returns the tokens
+        fn protect_tokens(&self, text: &mut TrackedText, first: usize) -> Vec<String> {
+            let mut tokens = vec![];
+            let mut edits = vec![];
+            for captures in self.token_rule.captures_iter(text.as_str()) {
+                let Some(found) = captures.name("path").or_else(|| captures.name("token")) else {
+                    continue;
+                };
+                let token = self.trim_token(found.as_str());
+                let Some(placeholder) = placeholder(first + tokens.len()) else {
+                    break;
+                };
+                if !token.is_empty() {
+                    tokens.push(token.to_string());
+                    edits.push((found.start()..found.start() + token.len(), Cow::Owned(placeholder.to_string())));
+                }
+            }
+            text.replace(edits);
+            tokens
+        }
+
+        // a closing bracket belongs to the token when the token opens it, as in Wikipedia URLs
+        fn trim_token<'a>(&self, mut token: &'a str) -> &'a str {
+            let unbalanced = |token: &str, open: char, close: char| token.matches(close).count() > token.matches(open).count();
+            while let Some(last) = token.chars().next_back() {
+                let trailing = match last {
+                    ')' => unbalanced(token, '(', ')'),
+                    ']' => unbalanced(token, '[', ']'),
+                    _ => ".,;:!?'\"}>»”’".contains(last) || self.sentence_end_punctuation.contains(&last),
+                };
+                if !trailing {
+                    break;
+                }
+                token = &token[..token.len() - last.len_utf8()];
+            }
+            token
+        }
+
+        // splits the text at the line breaks the newline policy makes boundaries
+        fn segment_lines(&self, text: TrackedText) -> Result<Vec<Sentence>, Error> {
+            if self.newlines == Newlines::Ignore {
+                return self.segment_block(text);
+            }
+            let mut sentences = vec![];
+            let mut start = 0;
+            for (index, _) in text.as_str().match_indices('\n') {
+                let boundary = match self.newlines {
+                    Newlines::Heuristic => self.line_start_rule.is_match(&text.as_str()[index + 1..]),
+                    _ => true,
+                };
+                if boundary {
+                    sentences.extend(self.segment_block(text.slice(start..index))?);
+                    start = index + 1;
+                }
+            }
+            sentences.extend(self.segment_block(text.slice(start..text.len()))?);
+            Ok(sentences)
+        }
+
+        fn segment_block(&self, text: TrackedText) -> Result<Vec<Sentence>, Error> {
+            let mut masked_string = text;
+            for rule in &self.rules {
+                let mut edits = vec![];
+                match rule {
+                    Rule::Replace(regex, replacement) => {
+                        for found in regex.find_iter(masked_string.as_str()) {
+                            let found = found.map_err(|error| Error::Matching(error.to_string()))?;
+                            edits.push((found.range(), Cow::Borrowed(replacement.as_str())));
+                        }
+                    }
+                    Rule::MaskDomains(regex) => {
+                        for found in regex.find_iter(masked_string.as_str()) {
+                            edits.push((found.range(), Cow::Owned(found.as_str().replace(".", "&^&"))));
+                        }
+                    }
+                }
+                masked_string.replace(edits);
+            }
+
+            if self.config.inverted_marks {
+                mask_inverted_pairs(&mut masked_string);
+            }
+
+            // step 17 : sentence segmentation and unmask
+            let mut segmented_sentence_candidates: Vec<Candidate> = vec![];
+            let mut sentence_start = 0;
+            let quotations = self.quotations(masked_string.as_str());
+            // closing marks of the quotations opened so far, and whether each is short enough
+            // to stay in one sentence
+            let mut open_quotes: Vec<(char, bool)> = vec![];
+
+            for (index, ch) in masked_string.as_str().char_indices() {
+                // already part of the previous sentence
+                if index < sentence_start {
+                    continue;
                 }
+                if let Some(&(_, close)) = self.quote_pairs.iter().find(|(open, _)| *open == ch) {
+                    // a quotation that is never closed holds no sentence
+                    if let Ok(found) = quotations.binary_search_by_key(&index, |&(start, _)| start) {
+                        open_quotes.push((close, quotations[found].1));
+                    }
+                } else if open_quotes.last().is_some_and(|&(close, _)| close == ch) {
+                    open_quotes.pop();
+                } else if self.sentence_end_punctuation.contains(&ch) {
+                    let mut end = index + ch.len_utf8();
+                    let closed = open_quotes.len();
+                    for next in masked_string.as_str()[end..].chars() {
+                        if open_quotes.last().is_none_or(|&(close, _)| close != next) {
+                            break;
+                        }
+                        open_quotes.pop();
+                        end += next.len_utf8();
+                    }
+                    let rest = &masked_string.as_str()[end..];
+                    match open_quotes.last() {
+                        // within short quoted speech
+                        Some((_, true)) => continue,
+                        // a long quotation ends sentences at its own terminators
+                        Some(_) => {}
+                        None if open_quotes.len() < closed && self.config.quotes.continuations.iter().any(|word| rest.trim_start().starts_with(word.as_str())) => continue,
+                        None => {}
+                    }
+                    if let Some(trailing_emoji) = self.trailing_emoji_rule.as_ref().and_then(|rule| rule.find(&masked_string.as_str()[end..])) {
+                        end += trailing_emoji.end();
+                    }
+                    let mut full_sentence_candidate = masked_string.slice(sentence_start..index).trim();
+                    full_sentence_candidate.push_slice(&masked_string, index..end);
+                    let full_sentence_candidate = self.finish_candidate(full_sentence_candidate)?;
+                    if full_sentence_candidate.len() > 2 {
+                        segmented_sentence_candidates.push(Candidate::new(full_sentence_candidate, Some(ch), None));
+                    }
+                    sentence_start = end;
+                } else if ch.is_whitespace() && open_quotes.is_empty() && self.ends_with_final_ending(&masked_string.as_str()[sentence_start..index]) {
+                    // an unpunctuated sentence, unless punctuation follows after all or a removed
+                    // comma or colon joined the clauses
+                    let next = masked_string.as_str()[index..].trim_start().chars().next();
+                    if masked_string.adjacent_at(index) && next.is_some_and(|next| !ALL_PUNCTUATIONS.contains(next)) {
+                        let full_sentence_candidate = self.finish_candidate(masked_string.slice(sentence_start..index).trim())?;
+                        if full_sentence_candidate.len() > 2 {
+                            segmented_sentence_candidates.push(Candidate::new(full_sentence_candidate, None, None));
+                        }
+                        sentence_start = index;
+                    }
+                }
+            }
+
+            let mut full_sentence_candidate = masked_string.slice(sentence_start..masked_string.len()).trim();
+            // a blank fragment would otherwise become a sentence made of the appended period alone
+            if !full_sentence_candidate.as_str().is_empty() {
+                // a fragment already closed by punctuation of another script keeps it, otherwise
+                // step 6 would strip the appended period together with it on the next pass
+                let mut terminator = full_sentence_candidate.as_str().chars().next_back()
+                    .filter(|&c| ALL_PUNCTUATIONS.contains(c) && !NON_TERMINATORS.contains(c));
+                let trailing_fragment = match terminator {
+                    Some(_) => None,
+                    None => Some(self.trailing_fragment),
+                };
+                if trailing_fragment == Some(TrailingFragment::AppendPeriod) {
+                    let end = full_sentence_candidate.span().end;
+                    full_sentence_candidate.push_str(self.config.period.as_str(), end..end);
+                    terminator = Some(self.period);
+                }
+                self.unmask(&mut full_sentence_candidate);
+                full_sentence_candidate = full_sentence_candidate.trim();
+                if full_sentence_candidate.len() > 2 && trailing_fragment != Some(TrailingFragment::Drop) {
+                    segmented_sentence_candidates.push(Candidate::new(full_sentence_candidate, terminator, trailing_fragment));
+                }
+            }
+
+            let mut final_segmented_sentences: Vec<Candidate> = vec![];
+
+            if !self.config.have_capital_letter {
+                final_segmented_sentences = segmented_sentence_candidates;
             } else {
-                sentence.push(ch);
+                // if the first letter is lowercase, merge it with the previous sentence
+                for sentence_candidate in segmented_sentence_candidates {
+                    let first_char = sentence_candidate.text.as_str().chars().next().unwrap();
+                    match final_segmented_sentences.last_mut() {
+                        Some(previous_sentence) if first_char.is_lowercase() => {
+                            let end = previous_sentence.text.span().end;
+                            previous_sentence.text.push_str(" ", end..end);
+                            previous_sentence.text.push_slice(&sentence_candidate.text, 0..sentence_candidate.text.len());
+                            previous_sentence.terminator = sentence_candidate.terminator;
+                            previous_sentence.trailing_fragment = sentence_candidate.trailing_fragment;
+                            previous_sentence.merged = true;
+                        }
+                        _ => final_segmented_sentences.push(sentence_candidate),
+                    }
+                }
             }
+
+            #[cfg(feature = "thai")]
+            if let Some(model) = &self.thai_model {
+                let mut sentences = vec![];
+                for chunk in final_segmented_sentences {
+                    let pieces = model.segment(chunk.text.as_str())?;
+                    let last = pieces.len().saturating_sub(1);
+                    for (i, piece) in pieces.into_iter().enumerate() {
+                        // only the last piece ends at the punctuation of the chunk
+                        let mut sentence = Candidate::new(chunk.text.slice(piece), None, None);
+                        sentence.merged = chunk.merged;
+                        if i == last {
+                            sentence.terminator = chunk.terminator;
+                            sentence.trailing_fragment = chunk.trailing_fragment;
+                        }
+                        sentences.push(sentence.into_sentence());
+                    }
+                }
+                return Ok(sentences);
+            }
+
+            Ok(final_segmented_sentences.into_iter().map(Candidate::into_sentence).collect())
+        }
+
+        /// Segments the prose of a markdown document. Headings, list items, table cells and
+        /// other blocks end sentences, code blocks, HTML and images are skipped, and inline code
+        /// and autolinks are kept intact. Spans point into the markdown source.
+        #[cfg(feature = "markdown")]
+        pub fn segment_markdown(&self, text: &str) -> Result<Vec<Sentence>, Error> {
+            self.segment_blocks(markdown::blocks(text))
+        }
+
+        /// Segments the visible text of an HTML document. Block elements such as `<p>`, `<li>`,
+        /// `<h1>` to `<h6>`, `<td>` and `<br>` end sentences, `<script>` and `<style>` are
+        /// skipped and entities are decoded. Spans point into the HTML source.
+        pub fn segment_html(&self, html: &str) -> Result<Vec<Sentence>, Error> {
+            self.segment_blocks(html::blocks(html))
+        }
+
+        /// Segments the text of SRT or WebVTT subtitles. The cues are joined, so a sentence may
+        /// span several cues and a cue may hold several sentences. Spans point into the
+        /// subtitle file.
+        pub fn segment_subtitles(&self, subtitles: &str) -> Result<Vec<TimedSentence>, Error> {
+            let parsed = subtitle::parse(subtitles)?;
+            let sentences = self.segment_blocks(parsed.blocks)?;
+            Ok(sentences
+                .into_iter()
+                .map(|sentence| TimedSentence {
+                    start: subtitle::time_at(&parsed.cues, sentence.span.start),
+                    end: subtitle::time_at(&parsed.cues, sentence.span.end),
+                    sentence,
+                })
+                .collect())
         }
 
-        if !sentence.is_empty() {
-            let mut full_sentence_candidate = sentence.trim().to_string();
-            full_sentence_candidate.push(config.period.clone().as_str().chars().next().unwrap());
-            full_sentence_candidate = full_sentence_candidate.replace("&ᓷ&", config.question_mark.clone().as_str());
-            full_sentence_candidate = full_sentence_candidate.replace("&ᓴ&", config.exclamation_mark.clone().as_str());
-            full_sentence_candidate = full_sentence_candidate.replace("&^&", ".");
-            full_sentence_candidate = full_sentence_candidate.trim().to_string();
-            if full_sentence_candidate.len() > 2 {
-                segmented_sentence_candidates.push(full_sentence_candidate);
+        fn segment_blocks(&self, blocks: Blocks) -> Result<Vec<Sentence>, Error> {
+            let mut sentences = vec![];
+            for block in blocks.blocks {
+                sentences.extend(self.segment_tracked(block, &blocks.tokens)?);
             }
+            Ok(sentences)
         }
 
-        let mut final_segmented_sentences: Vec<String> = vec![];
+        /// Segments documents in parallel on the rayon thread pool. The workers share this
+        /// segmenter, so the rules and the Thai model are compiled and loaded once. The results
+        /// are in the order of `documents`.
+        #[cfg(feature = "rayon")]
+        pub fn segment_batch(&self, documents: &[&str]) -> Result<Vec<Vec<Sentence>>, Error> {
+            documents.par_iter().map(|document| self.segment(document)).collect()
+        }
 
-        if !config.have_capital_letter {
-            final_segmented_sentences = segmented_sentence_candidates;
-        } else {
-            // if the first letter is lowercase, merge it with the previous sentence
-            let mut previous_sentence = String::new();
-            for sentence_candidate in segmented_sentence_candidates {
-                let first_char = sentence_candidate.chars().next().unwrap();
-                if first_char.is_lowercase() {
-                    previous_sentence.push_str(" ");
-                    previous_sentence.push_str(&sentence_candidate);
-                } else {
-                    if previous_sentence.len() > 0 {
-                        final_segmented_sentences.push(previous_sentence.trim().to_string());
+        // start of each quotation in `text` that is closed again, and whether it holds at most
+        // MAX_QUOTED_SENTENCES sentences; closing an outer quotation leaves the inner ones open
+        fn quotations(&self, text: &str) -> Vec<(usize, bool)> {
+            let mut quotations = vec![];
+            // start, closing mark and terminators of the quotations opened so far
+            let mut open: Vec<(usize, char, usize)> = vec![];
+            for (index, ch) in text.char_indices() {
+                if let Some(&(_, close)) = self.quote_pairs.iter().find(|(open, _)| *open == ch) {
+                    open.push((index, close, 0));
+                } else if let Some(position) = open.iter().rposition(|&(_, close, _)| close == ch) {
+                    let start = open[position].0;
+                    let terminators: usize = open.drain(position..).map(|(_, _, terminators)| terminators).sum();
+                    quotations.push((start, terminators <= MAX_QUOTED_SENTENCES));
+                    if let Some(outer) = open.last_mut() {
+                        outer.2 += terminators;
+                    }
+                } else if self.sentence_end_punctuation.contains(&ch) {
+                    if let Some(innermost) = open.last_mut() {
+                        innermost.2 += 1;
                     }
-                    previous_sentence = sentence_candidate;
                 }
             }
+            quotations.sort_unstable();
+            quotations
+        }
+
+        fn ends_with_final_ending(&self, text: &str) -> bool {
+            self.config.final_endings.iter().any(|ending| {
+                let mut chars = ending.chars();
+                match chars.next().filter(|ch| HANGUL_FINALS.contains(ch)) {
+                    // an ending such as `ㅆ다` matches a syllable with that final consonant, as the
+                    // contracted `왔다` and `갔다`, followed by the rest of the ending
+                    Some(consonant) => text
+                        .strip_suffix(chars.as_str())
+                        .and_then(|rest| rest.chars().next_back())
+                        .is_some_and(|syllable| hangul_final(syllable) == Some(consonant)),
+                    None => text.ends_with(ending.as_str()),
+                }
+            })
+        }
 
-            if previous_sentence.len() > 0 {
-                final_segmented_sentences.push(previous_sentence.trim().to_string());
+        // unmasks a sentence and collapses the white space left by the removed characters
+        fn finish_candidate(&self, mut candidate: TrackedText) -> Result<TrackedText, Error> {
+            self.unmask(&mut candidate);
+            candidate = candidate.trim();
+            let mut edits = vec![];
+            for found in self.extra_white_space_rule.find_iter(candidate.as_str()) {
+                let found = found.map_err(|error| Error::Matching(error.to_string()))?;
+                edits.push((found.range(), Cow::Borrowed(" ")));
             }
+            candidate.replace(edits);
+            Ok(candidate)
+        }
+
+        fn unmask(&self, sentence: &mut TrackedText) {
+            sentence.replace_literal("&ᓷ&", self.config.question_mark.as_str());
+            sentence.replace_literal("&ᓴ&", self.config.exclamation_mark.as_str());
+            sentence.replace_literal("&^&", ".");
+            sentence.replace_literal("&ᓵ&", ";");
         }
+    }
 
-        for (k, v) in abbreviations {
-            unsafe{
-                let ptr_k = k as *const _;
-                let ptr_v = v as *const _;
-                core::ptr::drop_in_place(&ptr_k as *const _ as *mut Box<[&str; 3]>);
-                core::ptr::drop_in_place(&ptr_v as *const _ as *mut Box<[&str; 3]>);
+    // the final consonant of a precomposed Hangul syllable, e.g. `ㅆ` for `왔`
+    fn hangul_final(syllable: char) -> Option<char> {
+        let index = (syllable as u32).checked_sub(0xAC00).filter(|&index| index < 11172)? % 28;
+        index.checked_sub(1).map(|index| HANGUL_FINALS[index as usize])
+    }
+
+    // a `?` or `!` that closes a `¿` or `¡` is part of the sentence when the clause goes on, as
+    // in "¿Vienes?, dijo ella", or when it is nested in another pair
+    fn mask_inverted_pairs(text: &mut TrackedText) {
+        let mut open = 0usize;
+        let mut edits = vec![];
+        let string = text.as_str();
+        for (index, ch) in string.char_indices() {
+            match ch {
+                '¿' | '¡' => open += 1,
+                '?' | '!' if open > 0 => {
+                    open -= 1;
+                    let end = index + ch.len_utf8();
+                    let continues = string[end..].trim_start().starts_with(|next: char| next.is_lowercase() || ",;:".contains(next));
+                    if open > 0 || continues {
+                        edits.push((index..end, Cow::Borrowed(if ch == '?' { "&ᓷ&" } else { "&ᓴ&" })));
+                    }
+                }
+                _ if ALL_PUNCTUATIONS.contains(ch) && !NON_TERMINATORS.contains(ch) => open = 0,
+                _ => {}
+            }
+        }
+        text.replace(edits);
+    }
+
+    // the alphabet is pasted into character classes, so it must compile on its own and must
+    // not close the class early
+    fn check_alphabets(alphabets: &str) -> Result<(), Error> {
+        let invalid = |message: String| Error::InvalidAlphabet { alphabets: alphabets.to_string(), message };
+        let mut escaped = false;
+        for ch in alphabets.chars() {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == ']' {
+                return Err(invalid("unescaped `]` closes the character class".to_string()));
             }
         }
-	
-        final_segmented_sentences
+        if escaped {
+            return Err(invalid("trailing `\\`".to_string()));
+        }
+        Regex::new(&format!("[{}]", alphabets)).map_err(|error| invalid(error.to_string()))?;
+        Ok(())
     }
 
-    pub fn abazanian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
+    fn single_char(punctuation: &str) -> Result<char, Error> {
+        let mut chars = punctuation.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Ok(ch),
+            _ => Err(Error::InvalidPunctuation(punctuation.to_string())),
+        }
+    }
 
-        process(text, config)
+    fn char_pair(pair: &str) -> Result<(char, char), Error> {
+        let mut chars = pair.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(open), Some(close), None) if open != close => Ok((open, close)),
+            _ => Err(Error::InvalidQuotes(pair.to_string())),
+        }
     }
 
-    pub fn abkhazian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
+    fn rule(pattern: &str) -> Result<Regex, Error> {
+        RegexBuilder::new(pattern)
+            .backtrack_limit(BACKTRACK_LIMIT)
+            .build()
+            .map_err(|error| Error::InvalidPattern { pattern: pattern.to_string(), message: error.to_string() })
+    }
 
-        process(text, config)
+    fn substitution(pattern: &str, replacement: &str) -> Result<Rule, Error> {
+        Ok(Rule::Replace(rule(pattern)?, replacement.to_string()))
     }
 
-    pub fn afrikaans(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
+    // the segmenter of each built-in language, compiled on its first use and shared by the uses
+    // after it; threads racing on the first use may each compile one, and the first one is kept
+    fn shared_segmenter(name: &str) -> Result<&'static Segmenter, Error> {
+        static SEGMENTERS: [OnceLock<Segmenter>; REGISTRY.len()] = [const { OnceLock::new() }; REGISTRY.len()];
+        let index = REGISTRY
+            .iter()
+            .position(|language| language.name == name)
+            .ok_or_else(|| Error::UnknownLanguage(name.to_string()))?;
+        if let Some(segmenter) = SEGMENTERS[index].get() {
+            return Ok(segmenter);
+        }
+        let segmenter = REGISTRY[index].segmenter()?;
+        Ok(SEGMENTERS[index].get_or_init(|| segmenter))
+    }
 
-        process(text, config)
+    // the built-in configurations always compile, so only the Thai model or a failing match can
+    // panic here
+    fn process(text: &str, name: &str) -> Vec<String> {
+        let segmenter = shared_segmenter(name).expect("Unable to build the segmenter");
+        segmenter.segment(text).expect("Unable to segment text")
+            .into_iter().map(|sentence| sentence.text).collect()
     }
 
-    pub fn albanian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÇçËë".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
+    pub fn abazanian(text: &str) -> Vec<String> {
+        process(text, "abazanian")
+    }
 
-        process(text, config)
+    pub fn abkhazian(text: &str) -> Vec<String> {
+        process(text, "abkhazian")
     }
 
-    pub fn amharic(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{1200}-\u{137F}".to_string(),
-            have_capital_letter: false,
-            period: "\u{1362}".to_string(),
-            question_mark: "\u{1367}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
+    pub fn afrikaans(text: &str) -> Vec<String> {
+        process(text, "afrikaans")
+    }
 
-        process(text, config)
+    pub fn albanian(text: &str) -> Vec<String> {
+        process(text, "albanian")
     }
 
-    pub fn arabic(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["?".to_string(), "\u{06D4}".to_string()],
-        };
+    pub fn amharic(text: &str) -> Vec<String> {
+        process(text, "amharic")
+    }
 
-        process(text, config)
+    pub fn arabic(text: &str) -> Vec<String> {
+        process(text, "arabic")
     }
 
     pub fn armenian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0531}-\u{058F}".to_string(),
-            have_capital_letter: true,
-            period: "\u{0589}".to_string(),
-            question_mark: "\u{055E}".to_string(),
-            exclamation_mark: "\u{055C}".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "armenian")
     }
 
     pub fn assamese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0980}-\u{09FF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{002E}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0964}".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "assamese")
     }
 
     pub fn azerbaijani(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÇŞĞÖÜİƏçşğıöüə".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "azerbaijani")
     }
 
     pub fn balinese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{1B00}-\u{1B7F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{1B4F}".to_string(), "\u{1B5A}".to_string(), "\u{1B7D}".to_string(), "\u{1B7E}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "balinese")
     }
 
     pub fn balochi(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "balochi")
     }
 
     pub fn bambara(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0100}-\u{017F}\u{0180}-\u{024F}\u{0250}-\u{02AF}\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}\u{07C0}-\u{07FF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "\u{07F9}".to_string(),
-            other_punctuations: vec!["\u{06D4}".to_string(), "\u{07F7}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "bambara")
     }
 
     pub fn basque(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÑÇñç".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "basque")
     }
 
     pub fn beja(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["?".to_string(), "\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "beja")
     }
 
     pub fn belarusian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "belarusian")
     }
 
     pub fn bengali(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0980}-\u{09FF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "bengali")
     }
 
     pub fn brahui(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "brahui")
     }
 
     pub fn bhojpuri(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}\u{11080}-\u{110CF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "bhojpuri")
     }
 
     pub fn bosnian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZČĆDžĐŠŽčćdžđšž".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "bosnian")
     }
 
     pub fn bulgarian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "bulgarian")
     }
 
     pub fn burmese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{1000}-\u{109F}".to_string(),
-            have_capital_letter: false,
-            period: "\u{104B}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string()],
-        };
-
-        process(text, config)
+        process(text, "burmese")
     }
 
     pub fn buryat(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "buryat")
     }
 
     pub fn catalan(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÀÁÂÈÉÊÌÍÒÓÔÙÚÜÇàáâèéêìíòóôùúüç".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "catalan")
     }
 
     pub fn cebuano(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÑñ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "cebuano")
     }
 
     pub fn chechen(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "chechen")
     }
 
     pub fn chinese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{4E00}-\u{9FFF}".to_string(),
-            have_capital_letter: false,
-            period: "。".to_string(),
-            question_mark: "？".to_string(),
-            exclamation_mark: "！".to_string(),
-            other_punctuations: vec!["!".to_string(), "?".to_string()],
-        };
-
-        process(text, config)
+        process(text, "chinese")
     }
 
     pub fn corsican(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "corsican")
     }
 
     pub fn creole(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÈèÒò".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "creole")
     }
 
-    // basically same as serbian
     pub fn croatian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZČĆŽŠĐčćžšđa-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "croatian")
     }
 
     pub fn czech(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĚŠČŘŽÝÁÍÉÚŮÓěščřžýáíéúůó".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "czech")
     }
 
     pub fn danish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZØÆÅøæå".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "danish")
     }
 
     pub fn dargwa(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "dargwa")
     }
 
     pub fn dinka(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÄäËëƐ̈ɛ̈ƔɣÏïŊŋÖöƆɔƆ̈ɔ̈Üü".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "dinka")
     }
 
     pub fn dutch(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÄËÏÖÜÇÀÈÌÒÙäëïöüçàèìòù".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "dutch")
     }
 
     pub fn english(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "english")
     }
 
     pub fn erzya(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "erzya")
     }
 
     pub fn esperanto(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĈĉĜĝĤĥĴĵŜŝŬŭ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "esperanto")
     }
 
     pub fn estonian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZŠŽÕÄÖÜšžõäöü".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "estonian")
     }
 
     pub fn finnish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÄÖÅäöå".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "finnish")
     }
 
     pub fn french(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÀÂÆÇÉÈÊËÎÏÔŒÙÛÜŸàâæçéèêëîïôœùûüÿ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "french")
     }
 
     pub fn frisian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{00A0}-\u{00FF}\u{0100}-\u{017F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "frisian")
     }
 
     pub fn galician(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÑñ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "galician")
     }
 
     pub fn ganda(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÀÈÙÁÉÍÓÚàèùáéíóú".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "ganda")
     }
 
     pub fn gedeo(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{1200}-\u{137F}".to_string(),
-            have_capital_letter: false,
-            period: "\u{1362}".to_string(),
-            question_mark: "\u{1367}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "gedeo")
     }
 
     pub fn georgian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{10A0}-\u{10FF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "georgian")
     }
 
     pub fn german(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÄÖÜẞäöüß".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "german")
     }
 
     pub fn greek(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0370}-\u{03FF}\u{1F00}-\u{1FFF}\u{1D00}-\u{1D7F}\u{1D80}-\u{1DBF}\u{2100}-\u{214F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "greek")
     }
 
     pub fn guarani(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÃãẼẽG̃g̃ĨĩÑñÕõŨũỸỹ\u{02BC}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "guarani")
     }
 
     pub fn gujarati(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0A80}-\u{0AFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "gujarati")
     }
 
     pub fn hausa(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZƁɓƊɗƘƙƳƴ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "hausa")
     }
 
     pub fn hebrew(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0590}-\u{05FF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "hebrew")
     }
 
     pub fn hiligaynon(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0027}\u{00A0}-\u{00FF}\u{0100}-\u{017F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "hiligaynon")
     }
 
     pub fn hindi(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "hindi")
     }
 
     pub fn hungarian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÁÉÍÓÖŐÚÜáéíóöőúü".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "hungarian")
     }
 
     pub fn icelandic(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÁáÉéÍíÓóÚúÝýÞþÆæÖöÐð".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "icelandic")
     }
 
     pub fn ido(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "ido")
     }
 
     pub fn igbo(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZỊṄỌỤịṅọụ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "igbo")
     }
 
     pub fn indonesian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "A-Za-zÁáÉéÍíÓóÚúŃńÇçĐđ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "indonesian")
     }
 
     pub fn interlingua(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "interlingua")
     }
 
     pub fn irish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÁáÉéÍíÓóÚúÇç".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "irish")
     }
 
     pub fn italian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÀÈÉÌÒÙàèéìòù".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "italian")
     }
 
     pub fn japanese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{3040}-\u{309F}\u{30A0}-\u{30FF}\u{4000}-\u{9FFF}".to_string(),
-            have_capital_letter: false,
-            period: "。".to_string(),
-            question_mark: "？".to_string(),
-            exclamation_mark: "！".to_string(),
-            other_punctuations: vec![".".to_string()],
-        };
-
-        process(text, config)
+        process(text, "japanese")
     }
 
     pub fn javanese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÅåÉéĚěÓóÚú\u{A980}-\u{A9DF}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "javanese")
     }
 
     pub fn kabyle(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{00A0}-\u{00FF}\u{0100}-\u{017F}\u{0180}-\u{024F}\u{0250}-\u{02AF}\u{0300}-\u{036F}\u{1E00}-\u{1EFF}\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}\u{2D30}-\u{2D7F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "kabyle")
     }
 
     pub fn kangri(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}\u{11680}-\u{116CF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "kangri")
     }
 
     pub fn kannada(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0C80}-\u{0CFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "kannada")
     }
 
     pub fn kashmiri(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}\u{11180}-\u{111DF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{111C5}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{06D4}".to_string(), "\u{0964}".to_string(), "\u{0965}".to_string(), "\u{111C6}".to_string(), "\u{111C8}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "kashmiri")
     }
 
     pub fn kazakh(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "A-Za-zÁáÀàÂâÄäÇçEéÍíOóŞşUú\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "kazakh")
     }
 
     pub fn khmer(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "A-Za-z\u{1780}-\u{17FF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{17D4}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "\u{17D5}".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "khmer")
     }
 
     pub fn khoekhoe(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "khoekhoe")
     }
 
     pub fn kiga(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "kiga")
     }
 
     pub fn korean(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{AC00}-\u{D7A3}\u{4000}-\u{9FFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "korean")
     }
 
     pub fn kurdish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÇÊÎŞÛçêîşû".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "kurdish")
     }
 
     pub fn kyrgyz(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "kyrgyz")
     }
 
     pub fn lao(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0E80}-\u{0EFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "lao")
     }
 
     pub fn latgalian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĀāČčĒēĢģĶķĻļŅņŌōŠšŪūŽž".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "latgalian")
     }
 
     pub fn latin(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "latin")
     }
 
     pub fn latvian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĀČĒĢĪĶĻŅŌŖŠŪŽāčēģīķļņōŗšūž".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "latvian")
     }
 
     pub fn lithuanian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĄČĘĖĮŠŲŪŽąčęėįšųūž".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "lithuanian")
     }
 
     pub fn macedonian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "macedonian")
     }
 
     pub fn magahi(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "magahi")
     }
 
     pub fn malagasy(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "malagasy")
     }
 
-    // basically same as indonesian
     pub fn malay(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÁáÉéÍíÓóÚúŃńÇçĐđ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "malay")
     }
 
     pub fn malayalam(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0D00}-\u{0D7F}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "malayalam")
     }
 
     pub fn maltese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĊċĠġĦħŻż".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "maltese")
     }
 
     pub fn mandeali(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}\u{11680}-\u{116CF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "mandeali")
     }
 
     pub fn manipuri(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{ABC0}-\u{ABFF}".to_string(),
-            have_capital_letter: true,
-            period: "\u{ABEB}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string()],
-        };
-
-        process(text, config)
+        process(text, "manipuri")
     }
 
     pub fn maori(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĀāĒēĪīŌōŪū".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "maori")
     }
 
     pub fn marathi(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "marathi")
     }
 
     pub fn mongolian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
+        process(text, "mongolian")
+    }
 
-        process(text, config)
+    pub fn mongolian_traditional(text: &str) -> Vec<String> {
+        process(text, "mongolian_traditional")
     }
 
     pub fn nepali(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "nepali")
     }
 
     pub fn nkore(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "nkore")
     }
 
     pub fn norwegian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÆØÅæøå".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "norwegian")
     }
 
     pub fn oriya(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0B00}-\u{0B7F}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "oriya")
     }
 
     pub fn ossetian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "ossetian")
     }
 
     pub fn papiamento(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{00A0}-\u{00FF}\u{0100}-\u{017F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "papiamento")
     }
 
     pub fn pashto(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{06D4}".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string()],
-        };
-
-        process(text, config)
+        process(text, "pashto")
     }
 
     pub fn persian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "persian")
     }
 
     pub fn polish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĄĆĘŁŃÓŚŹŻąćęłńóśźż".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "polish")
     }
 
     pub fn portuguese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÀÁÂÃÄÇÉÊËÍÎÏÓÔÕÖÚÛÜàáâãäçéêëíîïóôõöúûü".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "portuguese")
     }
 
     pub fn punjabi_eastern(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0A00}-\u{0A7F}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{061F}".to_string(), "\u{0965}".to_string(), ".".to_string()],
-        };
-
-        process(text, config)
+        process(text, "punjabi_eastern")
     }
 
     pub fn punjabi_western(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["?".to_string(), "\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "punjabi_western")
     }
 
     pub fn quechuan(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0027}\u{00A0}-\u{00FF}\u{0100}-\u{017F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "quechuan")
     }
 
     pub fn romanian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĂÂÎȘȚăâîșț".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "romanian")
     }
 
     pub fn russian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "russian")
     }
 
     pub fn sanskrit(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "sanskrit")
     }
 
     pub fn santali(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{1C50}-\u{1C7F}".to_string(),
-            have_capital_letter: false,
-            period: "\u{1C7E}".to_string(),
-            question_mark: "\u{1C76}".to_string(),
-            exclamation_mark: "\u{1C7F}".to_string(),
-            other_punctuations: vec!["\u{0964}".to_string(), "\u{0965}".to_string(), ".".to_string()],
-        };
-
-        process(text, config)
+        process(text, "santali")
     }
 
     pub fn scottish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "scottish")
     }
 
     pub fn serbian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZščćđžŠČĆĐŽ\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "serbian")
     }
 
     pub fn shona(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZáÁâÂàÀéÉèÈíÍìÌóÓòÒúÚùÙñÑ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "shona")
     }
 
     pub fn sindhi(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}\u{0900}-\u{097F}\u{A8E0}-\u{A8FF}\u{11B00}-\u{11B5F}\u{1CD0}-\u{1CFF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![".".to_string(), "\u{0965}".to_string(), "\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "sindhi")
     }
 
     pub fn sinhala(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0D80}-\u{0DFF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0964}".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{0DF4}".to_string(), "\u{0965}".to_string(), ".".to_string()],
-        };
-
-        process(text, config)
+        process(text, "sinhala")
     }
 
     pub fn slovak(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÁÄČĎÉĽÍŇÓÔÚÝáäčďéľíňóôúý".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "slovak")
     }
 
     pub fn slovenian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZČŠŽčšž".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "slovenian")
     }
 
     pub fn somali(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÀàÁáÂâÆæÈèÉéÊêËëÍíÎîÒòÓóÔôÙùÚúÛû".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "somali")
     }
 
     pub fn sotho(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÀàÁáÈèÉéÊêÍíÒòÓóÔôÙùÚú".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "sotho")
     }
 
     pub fn spanish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÁÉÍÓÚÑáéíóúñ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "spanish")
     }
 
     pub fn sundanese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["?".to_string(), "\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "sundanese")
     }
 
     pub fn swahili(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "swahili")
     }
 
     pub fn swedish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÅÄÖåäö".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "swedish")
     }
 
     pub fn tagalog(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "tagalog")
     }
 
     pub fn tamil(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0B80}-\u{0BFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "tamil")
     }
 
     pub fn tatar(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "tatar")
     }
 
     pub fn telugu(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0C00}-\u{0C7F}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{061F}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "telugu")
     }
 
     pub fn tetum(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "tetum")
     }
 
-    // Fuck muay thai!!!!!!!
     #[cfg(feature = "thai")]
     pub fn thai(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0E00}-\u{0E7F}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        let first_process = process(text, config);
-
-        let segmenter = thai_processor::ThaiSegmenter::new();
-        let mut final_sentences = Vec::new();
-        for first_chunk in first_process {
-            let sentences = segmenter.segment(&first_chunk);
-            for sentence in sentences {
-                final_sentences.push(sentence.to_string());
-            }
-        }
-
-        final_sentences
+        process(text, "thai")
     }
 
     pub fn tibetan(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0F00}-\u{0FFF}".to_string(),
-            have_capital_letter: false,
-            period: "\u{0F0D}".to_string(),
-            question_mark: "\u{2048}".to_string(),
-            exclamation_mark: "\u{0FC8}".to_string(),
-            other_punctuations: vec!["\u{0F0E}".to_string(), "\u{0F12}".to_string(), "\u{0F00}".to_string(), "\u{0F01}".to_string(), "\u{0F09}".to_string(), "\u{0F0A}".to_string(), ".".to_string()],
-        };
-
-        process(text, config)
+        process(text, "tibetan")
     }
 
     pub fn tigrinya(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{1200}-\u{137F}".to_string(),
-            have_capital_letter: false,
-            period: "\u{1362}".to_string(),
-            question_mark: "\u{1367}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "tigrinya")
     }
 
     pub fn tsonga(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "tsonga")
     }
 
     pub fn tswana(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "tswana")
     }
 
     pub fn turkish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZĞğİıÇçŞşÖöÜü".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "turkish")
     }
     
     pub fn turkmen(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÄäÇçĞğÑñÖöŞşÜü".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "turkmen")
     }
     
     pub fn ukrainian(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "ukrainian")
     }
 
     pub fn urdu(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "urdu")
     }
 
     pub fn uyghur(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "\u{061F}".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "uyghur")
     }
 
     pub fn uzbek(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZŌŞÇḠōşçḡ\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "uzbek")
     }
 
     pub fn vietnamese(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÀÁÂÃÈÉÊÌÍÒÓÔÕÙÚĂĐĨŨƠƯàáâãèéêìíòóôõùúăđĩũơưỲÝỴỶỸỳýỵỷỹ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "vietnamese")
     }
 
     pub fn volapuk(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZäöüÄÖÜ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "volapuk")
     }
 
     pub fn welsh(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "welsh")
     }
 
     pub fn wolof(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{00A0}-\u{00FF}\u{0100}-\u{017F}\u{0180}-\u{024F}\u{0250}-\u{02AF}\u{0300}-\u{036F}\u{1E00}-\u{1EFF}\u{0600}-\u{06FF}\u{08A0}-\u{08FF}\u{0870}-\u{089F}\u{FB50}-\u{FDFF}\u{10D00}-\u{10D3F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec!["\u{061F}".to_string(), "\u{06D4}".to_string()],
-        };
-
-        process(text, config)
+        process(text, "wolof")
     }
 
     pub fn xhosa(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "xhosa")
     }
 
     pub fn yakut(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0400}-\u{04FF}\u{0500}-\u{052F}\u{2DE0}-\u{2DFF}\u{A640}-\u{A69F}\u{1C80}-\u{1C8F}\u{1E030}-\u{1E08F}\u{1D2B}\u{1D78}\u{FE2E}\u{FE2F}".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "yakut")
     }
 
     pub fn yiddish(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z\u{0590}-\u{05FF}".to_string(),
-            have_capital_letter: false,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
-    }   
+        process(text, "yiddish")
+    }
 
     pub fn yoruba(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZẸỌṢẹọṣ".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "yoruba")
     }
 
     pub fn zaza(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-ZÇçÊêẌẍĞğÎîŞşÛû".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "zaza")
     }
 
     pub fn zulu(text: &str) -> Vec<String> {
-        let config = LanguageConfig {
-            alphabets: "a-zA-Z".to_string(),
-            have_capital_letter: true,
-            period: ".".to_string(),
-            question_mark: "?".to_string(),
-            exclamation_mark: "!".to_string(),
-            other_punctuations: vec![],
-        };
-
-        process(text, config)
+        process(text, "zulu")
+    }
+
+    /// Configurations of the built-in languages, for use with [`Segmenter::new`].
+    pub mod config {
+        use super::{LanguageConfig, Ordinals, Quotes};
+
+        /// Alphabet of the given Unicode scripts, with the combining marks and the `extras` of a
+        /// language. A script also covers the characters it shares with other scripts, e.g. the
+        /// Japanese prolonged sound mark `ー` or the Arabic comma.
+        pub fn scripts(scripts: &[&str], extras: &str) -> String {
+            let mut alphabets: String = scripts.iter().map(|script| format!(r"\p{{scx={}}}", script)).collect();
+            alphabets.push_str(r"\p{M}");
+            alphabets.push_str(extras);
+            alphabets
+        }
+
+        // month names and nouns separated by white space
+        fn ordinals(months: &str, nouns: &str) -> Ordinals {
+            Ordinals {
+                months: months.split_whitespace().map(str::to_string).collect(),
+                nouns: nouns.split_whitespace().map(str::to_string).collect(),
+                articles: vec![],
+            }
+        }
+
+        pub fn abazanian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn abkhazian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn afrikaans() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn albanian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn amharic() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Ethiopic"], ""),
+                have_capital_letter: false,
+                period: "\u{1362}".to_string(),
+                question_mark: "\u{1367}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn arabic() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["?".to_string(), "\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn armenian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Armenian"], ""),
+                have_capital_letter: true,
+                period: "\u{0589}".to_string(),
+                question_mark: "\u{055E}".to_string(),
+                exclamation_mark: "\u{055C}".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn assamese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Bengali"], ""),
+                have_capital_letter: false,
+                period: "\u{002E}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0964}".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn azerbaijani() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn balinese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Balinese"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{1B4F}".to_string(), "\u{1B5A}".to_string(), "\u{1B7D}".to_string(), "\u{1B7E}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn balochi() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn bambara() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic", "Nko"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "\u{07F9}".to_string(),
+                other_punctuations: vec!["\u{06D4}".to_string(), "\u{07F7}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn basque() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn beja() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["?".to_string(), "\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn belarusian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn bengali() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Bengali"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn brahui() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn bhojpuri() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Devanagari", "Kaithi"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn bosnian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "januar januara februar februara mart marta april aprila maj maja juni juna juli jula august augusta septembar septembra oktobar oktobra novembar novembra decembar decembra",
+                    "svjetski svjetskog stoljeće stoljeća vijek vijeka mjesto mjestu razred razredu sprat spratu izdanje kolo poglavlje dio",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn bulgarian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn burmese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Myanmar"], ""),
+                have_capital_letter: false,
+                period: "\u{104B}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn buryat() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn catalan() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: true,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn cebuano() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn chechen() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn chinese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Han"], ""),
+                have_capital_letter: false,
+                period: "。".to_string(),
+                question_mark: "？".to_string(),
+                exclamation_mark: "！".to_string(),
+                other_punctuations: vec!["!".to_string(), "?".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes {
+                    pairs: vec!["「」".to_string(), "『』".to_string(), "“”".to_string(), "（）".to_string(), "【】".to_string()],
+                    continuations: vec!["他说".to_string(), "她说".to_string(), "我说".to_string(), "你说".to_string(), "他们说".to_string(), "她们说".to_string(), "他问".to_string(), "她问".to_string(), "说道".to_string(), "问道".to_string(), "答道".to_string(), "笑道".to_string(), "，".to_string(), "、".to_string()],
+                },
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn corsican() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn creole() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        // basically same as serbian
+        pub fn croatian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "siječnja veljače ožujka travnja svibnja lipnja srpnja kolovoza rujna listopada studenoga studenog prosinca",
+                    "svjetski svjetskog stoljeće stoljeća mjesto mjestu razred razredu kat katu izdanje kolo poglavlje dio",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn czech() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "ledna února března dubna května června července srpna září října listopadu prosince",
+                    "světová světové světový století místo místě třída třídě patro patře vydání kolo kole ročník kapitola díl",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn danish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "januar februar marts april maj juni juli august september oktober november december",
+                    "verdenskrig århundrede årtusinde gang plads klasse sal etage udgave runde fødselsdag kapitel del bind",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn dargwa() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn dinka() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn dutch() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn english() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn erzya() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn esperanto() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn estonian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "jaanuar veebruar märts aprill mai juuni juuli august september oktoober november detsember jaanuaril veebruaril märtsil aprillil mail juunil juulil augustil septembril oktoobril novembril detsembril",
+                    "maailmasõda sajand sajandil koht kohal kord klass klassis korrus korrusel väljaanne voor peatükk osa",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn finnish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "tammikuuta helmikuuta maaliskuuta huhtikuuta toukokuuta kesäkuuta heinäkuuta elokuuta syyskuuta lokakuuta marraskuuta joulukuuta tammikuu helmikuu maaliskuu huhtikuu toukokuu kesäkuu heinäkuu elokuu syyskuu lokakuu marraskuu joulukuu",
+                    "maailmansota vuosisata vuosisadalla sija sijalle kerta kerran luokka luokalla kerros kerroksessa painos kierros luku osa",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn french() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn frisian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn galician() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: true,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn ganda() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn gedeo() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Ethiopic"], ""),
+                have_capital_letter: false,
+                period: "\u{1362}".to_string(),
+                question_mark: "\u{1367}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn georgian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Georgian"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn german() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals {
+                    articles: "der die das den dem des".split_whitespace().map(str::to_string).collect(),
+                    ..ordinals(
+                        "Januar Jänner Februar Feber März April Mai Juni Juli August September Oktober November Dezember",
+                        "Weltkrieg Jahrhundert Jahrtausend Mal Platz Rang Klasse Stock Etage Auflage Runde Spieltag Liga Geburtstag Jahrestag Kapitel Absatz Akt Teil Band Sinfonie",
+                    )
+                },
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn greek() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Greek"], "\u{0387}\u{00B7}"),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "\u{037E}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["?".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: true,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn guarani() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], "ʼ"),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: true,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn gujarati() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Gujarati"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn hausa() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn hebrew() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Hebrew"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn hiligaynon() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], "'"),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn hindi() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Devanagari"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn hungarian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals {
+                    articles: "a az".split_whitespace().map(str::to_string).collect(),
+                    ..ordinals(
+                        "január február március április május június július augusztus szeptember október november december",
+                        "világháború világháborúban század században helyezés helyen hely alkalommal osztály osztályban emelet emeleten kiadás forduló fordulóban fejezet rész kerület",
+                    )
+                },
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn icelandic() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "janúar febrúar mars apríl maí júní júlí ágúst september október nóvember desember",
+                    "heimsstyrjöldin öld sæti sinn bekkur hæð útgáfa umferð kafli hluti bindi",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn ido() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn igbo() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn indonesian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn interlingua() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn irish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn italian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn japanese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Hiragana", "Katakana", "Han"], ""),
+                have_capital_letter: false,
+                period: "。".to_string(),
+                question_mark: "？".to_string(),
+                exclamation_mark: "！".to_string(),
+                other_punctuations: vec![".".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes {
+                    pairs: vec!["「」".to_string(), "『』".to_string(), "“”".to_string(), "（）".to_string(), "【】".to_string()],
+                    continuations: vec!["と".to_string(), "って".to_string(), "など".to_string(), "なんて".to_string(), "、".to_string(), "，".to_string()],
+                },
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn javanese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Javanese"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn kabyle() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic", "Tifinagh"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn kangri() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Devanagari", "Takri"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn kannada() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Kannada"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn kashmiri() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic", "Devanagari", "Sharada"], ""),
+                have_capital_letter: false,
+                period: "\u{111C5}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{06D4}".to_string(), "\u{0964}".to_string(), "\u{0965}".to_string(), "\u{111C6}".to_string(), "\u{111C8}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn kazakh() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn khmer() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Khmer"], ""),
+                have_capital_letter: false,
+                period: "\u{17D4}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "\u{17D5}".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn khoekhoe() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn kiga() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn korean() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Hangul", "Han"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes {
+                    pairs: vec!["“”".to_string(), "‘’".to_string(), "「」".to_string(), "『』".to_string()],
+                    continuations: vec!["라고".to_string(), "이라고".to_string(), "하고".to_string(), "고".to_string(), "며".to_string(), "는".to_string()],
+                },
+                // the formal, polite and plain endings of statements and questions; `ㅆ다` and `ㄴ다`
+                // match the past and present plain forms of any verb, e.g. `왔다` and `간다`
+                final_endings: "니다 습니까 입니까 합니까 됩니까 어요 아요 여요 에요 예요 해요 세요 네요 군요 지요 든요 나요 래요 대요 까요 봐요 줘요 워요 와요 돼요 죠 ㅆ다 ㄴ다 이다 없다 같다"
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect(),
+                abbreviations: "Dr. Mr. Mrs. Ms. Prof. St. Jr. Sr. Inc. Ltd. Co. Corp. vs. etc. e.g. i.e."
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect(),
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn kurdish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn kyrgyz() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn lao() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Lao"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn latgalian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn latin() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn latvian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "janvāris februāris marts aprīlis maijs jūnijs jūlijs augusts septembris oktobris novembris decembris janvārī februārī martā aprīlī maijā jūnijā jūlijā augustā septembrī oktobrī novembrī decembrī",
+                    "gada gadā gadsimts gadsimtā pasaules vieta vietā reize reizi klase klasē stāvs stāvā izdevums kārta nodaļa daļa",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn lithuanian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn macedonian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn magahi() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Devanagari"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn malagasy() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        // basically same as indonesian
+        pub fn malay() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn malayalam() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Malayalam"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn maltese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn mandeali() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Devanagari", "Takri"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn manipuri() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Meetei_Mayek"], ""),
+                have_capital_letter: true,
+                period: "\u{ABEB}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn maori() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn marathi() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Devanagari"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn mongolian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        /// Mongolian in the traditional script, with its free variation selectors, the narrow
+        /// no-break space before suffixes and the vertical presentation forms of punctuation.
+        pub fn mongolian_traditional() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Mongolian"], "\u{202F}\u{200C}\u{200D}\u{FE10}-\u{FE19}"),
+                have_capital_letter: false,
+                period: "\u{1803}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{1809}".to_string(), "\u{FE16}".to_string(), "\u{FE15}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: true,
+            }
+        }
+
+        pub fn nepali() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Devanagari"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn nkore() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn norwegian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "januar februar mars april mai juni juli august september oktober november desember",
+                    "verdenskrig århundre årtusen gang plass klasse etasje utgave runde bursdag kapittel del bind",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn oriya() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Oriya"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn ossetian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn papiamento() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn pashto() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: "\u{06D4}".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn persian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn polish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "stycznia lutego marca kwietnia maja czerwca lipca sierpnia września października listopada grudnia",
+                    "wojna wojny wiek wieku miejsce miejscu raz klasa klasie piętro piętrze wydanie runda rundzie rozdział część",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn portuguese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn punjabi_eastern() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Gurmukhi"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{061F}".to_string(), "\u{0965}".to_string(), ".".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn punjabi_western() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["?".to_string(), "\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn quechuan() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], "'"),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn romanian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn russian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn sanskrit() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Devanagari"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn santali() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Ol_Chiki"], ""),
+                have_capital_letter: false,
+                period: "\u{1C7E}".to_string(),
+                question_mark: "\u{1C76}".to_string(),
+                exclamation_mark: "\u{1C7F}".to_string(),
+                other_punctuations: vec!["\u{0964}".to_string(), "\u{0965}".to_string(), ".".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        /// Scots (ISO 639-3 `sco`), the Germanic language of Lowland Scotland, not Scottish
+        /// Gaelic (`gla`).
+        pub fn scottish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn serbian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "januar januara februar februara mart marta april aprila maj maja jun juna jul jula avgust avgusta septembar septembra oktobar oktobra novembar novembra decembar decembra јануар јануара фебруар фебруара март марта април априла мај маја јун јуна јул јула август августа септембар септембра октобар октобра новембар новембра децембар децембра",
+                    "svetski svetskog vek veka mesto mestu razred razredu sprat spratu izdanje kolo poglavlje deo светски светског век века место месту разред разреду спрат спрату издање коло поглавље део",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn shona() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn sindhi() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic", "Devanagari"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![".".to_string(), "\u{0965}".to_string(), "\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn sinhala() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Sinhala"], ""),
+                have_capital_letter: false,
+                period: "\u{0964}".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{0DF4}".to_string(), "\u{0965}".to_string(), ".".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn slovak() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "januára februára marca apríla mája júna júla augusta septembra októbra novembra decembra",
+                    "svetová svetovej storočie storočí miesto mieste trieda triede poschodie poschodí vydanie kolo kole ročník kapitola diel",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn slovenian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "januar januarja februar februarja marec marca april aprila maj maja junij junija julij julija avgust avgusta september septembra oktober oktobra november novembra december decembra",
+                    "svetovna svetovne stoletje stoletju mesto mestu razred razredu nadstropje nadstropju izdaja krog poglavje del",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn somali() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn sotho() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn spanish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: true,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn sundanese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["?".to_string(), "\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn swahili() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn swedish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn tagalog() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn tamil() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Tamil"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn tatar() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn telugu() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Telugu"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{061F}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn tetum() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        // Fuck muay thai!!!!!!!
+        #[cfg(feature = "thai")]
+        pub fn thai() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Thai"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn tibetan() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Tibetan"], ""),
+                have_capital_letter: false,
+                period: "\u{0F0D}".to_string(),
+                question_mark: "\u{2048}".to_string(),
+                exclamation_mark: "\u{0FC8}".to_string(),
+                other_punctuations: vec!["\u{0F0E}".to_string(), "\u{0F12}".to_string(), "\u{0F00}".to_string(), "\u{0F01}".to_string(), "\u{0F09}".to_string(), "\u{0F0A}".to_string(), ".".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn tigrinya() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Ethiopic"], ""),
+                have_capital_letter: false,
+                period: "\u{1362}".to_string(),
+                question_mark: "\u{1367}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn tsonga() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn tswana() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn turkish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: ordinals(
+                    "Ocak Şubat Mart Nisan Mayıs Haziran Temmuz Ağustos Eylül Ekim Kasım Aralık",
+                    "Dünya yüzyıl yüzyılda sıra sırada kez defa sınıf sınıfta kat katta baskı tur turda bölüm kısım",
+                ),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn turkmen() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn ukrainian() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn urdu() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn uyghur() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "\u{061F}".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn uzbek() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn vietnamese() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn volapuk() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn welsh() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn wolof() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Arabic", "Hanifi_Rohingya"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec!["\u{061F}".to_string(), "\u{06D4}".to_string()],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn xhosa() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn yakut() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Cyrillic"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn yiddish() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin", "Hebrew"], ""),
+                have_capital_letter: false,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn yoruba() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn zaza() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+
+        pub fn zulu() -> LanguageConfig {
+            LanguageConfig {
+                alphabets: scripts(&["Latin"], ""),
+                have_capital_letter: true,
+                period: ".".to_string(),
+                question_mark: "?".to_string(),
+                exclamation_mark: "!".to_string(),
+                other_punctuations: vec![],
+                ordinals: Ordinals::default(),
+                inverted_marks: false,
+                semicolon_questions: false,
+                quotes: Quotes::default(),
+                final_endings: vec![],
+                abbreviations: vec![],
+                suffix_spaces: false,
+            }
+        }
+    }
+
+    /// A built-in language and its ISO 639 codes.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Language {
+        /// Name of the language, as used by the functions in this module and in [`config`].
+        pub name: &'static str,
+        /// ISO 639-1 code, for the languages that have one.
+        pub iso_639_1: Option<&'static str>,
+        /// ISO 639-3 code.
+        pub iso_639_3: &'static str,
+        pub config: fn() -> LanguageConfig,
+    }
+
+    /// Every built-in language configuration with its ISO 639 codes.
+    pub const REGISTRY: &[Language] = &[
+        Language { name: "abazanian", iso_639_1: None, iso_639_3: "abq", config: config::abazanian },
+        Language { name: "abkhazian", iso_639_1: Some("ab"), iso_639_3: "abk", config: config::abkhazian },
+        Language { name: "afrikaans", iso_639_1: Some("af"), iso_639_3: "afr", config: config::afrikaans },
+        Language { name: "albanian", iso_639_1: Some("sq"), iso_639_3: "sqi", config: config::albanian },
+        Language { name: "amharic", iso_639_1: Some("am"), iso_639_3: "amh", config: config::amharic },
+        Language { name: "arabic", iso_639_1: Some("ar"), iso_639_3: "ara", config: config::arabic },
+        Language { name: "armenian", iso_639_1: Some("hy"), iso_639_3: "hye", config: config::armenian },
+        Language { name: "assamese", iso_639_1: Some("as"), iso_639_3: "asm", config: config::assamese },
+        Language { name: "azerbaijani", iso_639_1: Some("az"), iso_639_3: "aze", config: config::azerbaijani },
+        Language { name: "balinese", iso_639_1: None, iso_639_3: "ban", config: config::balinese },
+        Language { name: "balochi", iso_639_1: None, iso_639_3: "bal", config: config::balochi },
+        Language { name: "bambara", iso_639_1: Some("bm"), iso_639_3: "bam", config: config::bambara },
+        Language { name: "basque", iso_639_1: Some("eu"), iso_639_3: "eus", config: config::basque },
+        Language { name: "beja", iso_639_1: None, iso_639_3: "bej", config: config::beja },
+        Language { name: "belarusian", iso_639_1: Some("be"), iso_639_3: "bel", config: config::belarusian },
+        Language { name: "bengali", iso_639_1: Some("bn"), iso_639_3: "ben", config: config::bengali },
+        Language { name: "brahui", iso_639_1: None, iso_639_3: "brh", config: config::brahui },
+        Language { name: "bhojpuri", iso_639_1: None, iso_639_3: "bho", config: config::bhojpuri },
+        Language { name: "bosnian", iso_639_1: Some("bs"), iso_639_3: "bos", config: config::bosnian },
+        Language { name: "bulgarian", iso_639_1: Some("bg"), iso_639_3: "bul", config: config::bulgarian },
+        Language { name: "burmese", iso_639_1: Some("my"), iso_639_3: "mya", config: config::burmese },
+        Language { name: "buryat", iso_639_1: None, iso_639_3: "bua", config: config::buryat },
+        Language { name: "catalan", iso_639_1: Some("ca"), iso_639_3: "cat", config: config::catalan },
+        Language { name: "cebuano", iso_639_1: None, iso_639_3: "ceb", config: config::cebuano },
+        Language { name: "chechen", iso_639_1: Some("ce"), iso_639_3: "che", config: config::chechen },
+        Language { name: "chinese", iso_639_1: Some("zh"), iso_639_3: "zho", config: config::chinese },
+        Language { name: "corsican", iso_639_1: Some("co"), iso_639_3: "cos", config: config::corsican },
+        Language { name: "creole", iso_639_1: Some("ht"), iso_639_3: "hat", config: config::creole },
+        Language { name: "croatian", iso_639_1: Some("hr"), iso_639_3: "hrv", config: config::croatian },
+        Language { name: "czech", iso_639_1: Some("cs"), iso_639_3: "ces", config: config::czech },
+        Language { name: "danish", iso_639_1: Some("da"), iso_639_3: "dan", config: config::danish },
+        Language { name: "dargwa", iso_639_1: None, iso_639_3: "dar", config: config::dargwa },
+        Language { name: "dinka", iso_639_1: None, iso_639_3: "din", config: config::dinka },
+        Language { name: "dutch", iso_639_1: Some("nl"), iso_639_3: "nld", config: config::dutch },
+        Language { name: "english", iso_639_1: Some("en"), iso_639_3: "eng", config: config::english },
+        Language { name: "erzya", iso_639_1: None, iso_639_3: "myv", config: config::erzya },
+        Language { name: "esperanto", iso_639_1: Some("eo"), iso_639_3: "epo", config: config::esperanto },
+        Language { name: "estonian", iso_639_1: Some("et"), iso_639_3: "est", config: config::estonian },
+        Language { name: "finnish", iso_639_1: Some("fi"), iso_639_3: "fin", config: config::finnish },
+        Language { name: "french", iso_639_1: Some("fr"), iso_639_3: "fra", config: config::french },
+        Language { name: "frisian", iso_639_1: Some("fy"), iso_639_3: "fry", config: config::frisian },
+        Language { name: "galician", iso_639_1: Some("gl"), iso_639_3: "glg", config: config::galician },
+        Language { name: "ganda", iso_639_1: Some("lg"), iso_639_3: "lug", config: config::ganda },
+        Language { name: "gedeo", iso_639_1: None, iso_639_3: "drs", config: config::gedeo },
+        Language { name: "georgian", iso_639_1: Some("ka"), iso_639_3: "kat", config: config::georgian },
+        Language { name: "german", iso_639_1: Some("de"), iso_639_3: "deu", config: config::german },
+        Language { name: "greek", iso_639_1: Some("el"), iso_639_3: "ell", config: config::greek },
+        Language { name: "guarani", iso_639_1: Some("gn"), iso_639_3: "grn", config: config::guarani },
+        Language { name: "gujarati", iso_639_1: Some("gu"), iso_639_3: "guj", config: config::gujarati },
+        Language { name: "hausa", iso_639_1: Some("ha"), iso_639_3: "hau", config: config::hausa },
+        Language { name: "hebrew", iso_639_1: Some("he"), iso_639_3: "heb", config: config::hebrew },
+        Language { name: "hiligaynon", iso_639_1: None, iso_639_3: "hil", config: config::hiligaynon },
+        Language { name: "hindi", iso_639_1: Some("hi"), iso_639_3: "hin", config: config::hindi },
+        Language { name: "hungarian", iso_639_1: Some("hu"), iso_639_3: "hun", config: config::hungarian },
+        Language { name: "icelandic", iso_639_1: Some("is"), iso_639_3: "isl", config: config::icelandic },
+        Language { name: "ido", iso_639_1: Some("io"), iso_639_3: "ido", config: config::ido },
+        Language { name: "igbo", iso_639_1: Some("ig"), iso_639_3: "ibo", config: config::igbo },
+        Language { name: "indonesian", iso_639_1: Some("id"), iso_639_3: "ind", config: config::indonesian },
+        Language { name: "interlingua", iso_639_1: Some("ia"), iso_639_3: "ina", config: config::interlingua },
+        Language { name: "irish", iso_639_1: Some("ga"), iso_639_3: "gle", config: config::irish },
+        Language { name: "italian", iso_639_1: Some("it"), iso_639_3: "ita", config: config::italian },
+        Language { name: "japanese", iso_639_1: Some("ja"), iso_639_3: "jpn", config: config::japanese },
+        Language { name: "javanese", iso_639_1: Some("jv"), iso_639_3: "jav", config: config::javanese },
+        Language { name: "kabyle", iso_639_1: None, iso_639_3: "kab", config: config::kabyle },
+        Language { name: "kangri", iso_639_1: None, iso_639_3: "xnr", config: config::kangri },
+        Language { name: "kannada", iso_639_1: Some("kn"), iso_639_3: "kan", config: config::kannada },
+        Language { name: "kashmiri", iso_639_1: Some("ks"), iso_639_3: "kas", config: config::kashmiri },
+        Language { name: "kazakh", iso_639_1: Some("kk"), iso_639_3: "kaz", config: config::kazakh },
+        Language { name: "khmer", iso_639_1: Some("km"), iso_639_3: "khm", config: config::khmer },
+        Language { name: "khoekhoe", iso_639_1: None, iso_639_3: "naq", config: config::khoekhoe },
+        Language { name: "kiga", iso_639_1: None, iso_639_3: "cgg", config: config::kiga },
+        Language { name: "korean", iso_639_1: Some("ko"), iso_639_3: "kor", config: config::korean },
+        Language { name: "kurdish", iso_639_1: Some("ku"), iso_639_3: "kur", config: config::kurdish },
+        Language { name: "kyrgyz", iso_639_1: Some("ky"), iso_639_3: "kir", config: config::kyrgyz },
+        Language { name: "lao", iso_639_1: Some("lo"), iso_639_3: "lao", config: config::lao },
+        Language { name: "latgalian", iso_639_1: None, iso_639_3: "ltg", config: config::latgalian },
+        Language { name: "latin", iso_639_1: Some("la"), iso_639_3: "lat", config: config::latin },
+        Language { name: "latvian", iso_639_1: Some("lv"), iso_639_3: "lav", config: config::latvian },
+        Language { name: "lithuanian", iso_639_1: Some("lt"), iso_639_3: "lit", config: config::lithuanian },
+        Language { name: "macedonian", iso_639_1: Some("mk"), iso_639_3: "mkd", config: config::macedonian },
+        Language { name: "magahi", iso_639_1: None, iso_639_3: "mag", config: config::magahi },
+        Language { name: "malagasy", iso_639_1: Some("mg"), iso_639_3: "mlg", config: config::malagasy },
+        Language { name: "malay", iso_639_1: Some("ms"), iso_639_3: "msa", config: config::malay },
+        Language { name: "malayalam", iso_639_1: Some("ml"), iso_639_3: "mal", config: config::malayalam },
+        Language { name: "maltese", iso_639_1: Some("mt"), iso_639_3: "mlt", config: config::maltese },
+        Language { name: "mandeali", iso_639_1: None, iso_639_3: "mjl", config: config::mandeali },
+        Language { name: "manipuri", iso_639_1: None, iso_639_3: "mni", config: config::manipuri },
+        Language { name: "maori", iso_639_1: Some("mi"), iso_639_3: "mri", config: config::maori },
+        Language { name: "marathi", iso_639_1: Some("mr"), iso_639_3: "mar", config: config::marathi },
+        Language { name: "mongolian", iso_639_1: Some("mn"), iso_639_3: "mon", config: config::mongolian },
+        Language { name: "mongolian_traditional", iso_639_1: None, iso_639_3: "mvf", config: config::mongolian_traditional },
+        Language { name: "nepali", iso_639_1: Some("ne"), iso_639_3: "nep", config: config::nepali },
+        Language { name: "nkore", iso_639_1: None, iso_639_3: "nyn", config: config::nkore },
+        Language { name: "norwegian", iso_639_1: Some("no"), iso_639_3: "nor", config: config::norwegian },
+        Language { name: "oriya", iso_639_1: Some("or"), iso_639_3: "ori", config: config::oriya },
+        Language { name: "ossetian", iso_639_1: Some("os"), iso_639_3: "oss", config: config::ossetian },
+        Language { name: "papiamento", iso_639_1: None, iso_639_3: "pap", config: config::papiamento },
+        Language { name: "pashto", iso_639_1: Some("ps"), iso_639_3: "pus", config: config::pashto },
+        Language { name: "persian", iso_639_1: Some("fa"), iso_639_3: "fas", config: config::persian },
+        Language { name: "polish", iso_639_1: Some("pl"), iso_639_3: "pol", config: config::polish },
+        Language { name: "portuguese", iso_639_1: Some("pt"), iso_639_3: "por", config: config::portuguese },
+        Language { name: "punjabi_eastern", iso_639_1: Some("pa"), iso_639_3: "pan", config: config::punjabi_eastern },
+        Language { name: "punjabi_western", iso_639_1: None, iso_639_3: "pnb", config: config::punjabi_western },
+        Language { name: "quechuan", iso_639_1: Some("qu"), iso_639_3: "que", config: config::quechuan },
+        Language { name: "romanian", iso_639_1: Some("ro"), iso_639_3: "ron", config: config::romanian },
+        Language { name: "russian", iso_639_1: Some("ru"), iso_639_3: "rus", config: config::russian },
+        Language { name: "sanskrit", iso_639_1: Some("sa"), iso_639_3: "san", config: config::sanskrit },
+        Language { name: "santali", iso_639_1: None, iso_639_3: "sat", config: config::santali },
+        Language { name: "scottish", iso_639_1: None, iso_639_3: "sco", config: config::scottish },
+        Language { name: "serbian", iso_639_1: Some("sr"), iso_639_3: "srp", config: config::serbian },
+        Language { name: "shona", iso_639_1: Some("sn"), iso_639_3: "sna", config: config::shona },
+        Language { name: "sindhi", iso_639_1: Some("sd"), iso_639_3: "snd", config: config::sindhi },
+        Language { name: "sinhala", iso_639_1: Some("si"), iso_639_3: "sin", config: config::sinhala },
+        Language { name: "slovak", iso_639_1: Some("sk"), iso_639_3: "slk", config: config::slovak },
+        Language { name: "slovenian", iso_639_1: Some("sl"), iso_639_3: "slv", config: config::slovenian },
+        Language { name: "somali", iso_639_1: Some("so"), iso_639_3: "som", config: config::somali },
+        Language { name: "sotho", iso_639_1: Some("st"), iso_639_3: "sot", config: config::sotho },
+        Language { name: "spanish", iso_639_1: Some("es"), iso_639_3: "spa", config: config::spanish },
+        Language { name: "sundanese", iso_639_1: Some("su"), iso_639_3: "sun", config: config::sundanese },
+        Language { name: "swahili", iso_639_1: Some("sw"), iso_639_3: "swa", config: config::swahili },
+        Language { name: "swedish", iso_639_1: Some("sv"), iso_639_3: "swe", config: config::swedish },
+        Language { name: "tagalog", iso_639_1: Some("tl"), iso_639_3: "tgl", config: config::tagalog },
+        Language { name: "tamil", iso_639_1: Some("ta"), iso_639_3: "tam", config: config::tamil },
+        Language { name: "tatar", iso_639_1: Some("tt"), iso_639_3: "tat", config: config::tatar },
+        Language { name: "telugu", iso_639_1: Some("te"), iso_639_3: "tel", config: config::telugu },
+        Language { name: "tetum", iso_639_1: None, iso_639_3: "tet", config: config::tetum },
+        #[cfg(feature = "thai")]
+        Language { name: "thai", iso_639_1: Some("th"), iso_639_3: "tha", config: config::thai },
+        Language { name: "tibetan", iso_639_1: Some("bo"), iso_639_3: "bod", config: config::tibetan },
+        Language { name: "tigrinya", iso_639_1: Some("ti"), iso_639_3: "tir", config: config::tigrinya },
+        Language { name: "tsonga", iso_639_1: Some("ts"), iso_639_3: "tso", config: config::tsonga },
+        Language { name: "tswana", iso_639_1: Some("tn"), iso_639_3: "tsn", config: config::tswana },
+        Language { name: "turkish", iso_639_1: Some("tr"), iso_639_3: "tur", config: config::turkish },
+        Language { name: "turkmen", iso_639_1: Some("tk"), iso_639_3: "tuk", config: config::turkmen },
+        Language { name: "ukrainian", iso_639_1: Some("uk"), iso_639_3: "ukr", config: config::ukrainian },
+        Language { name: "urdu", iso_639_1: Some("ur"), iso_639_3: "urd", config: config::urdu },
+        Language { name: "uyghur", iso_639_1: Some("ug"), iso_639_3: "uig", config: config::uyghur },
+        Language { name: "uzbek", iso_639_1: Some("uz"), iso_639_3: "uzb", config: config::uzbek },
+        Language { name: "vietnamese", iso_639_1: Some("vi"), iso_639_3: "vie", config: config::vietnamese },
+        Language { name: "volapuk", iso_639_1: Some("vo"), iso_639_3: "vol", config: config::volapuk },
+        Language { name: "welsh", iso_639_1: Some("cy"), iso_639_3: "cym", config: config::welsh },
+        Language { name: "wolof", iso_639_1: Some("wo"), iso_639_3: "wol", config: config::wolof },
+        Language { name: "xhosa", iso_639_1: Some("xh"), iso_639_3: "xho", config: config::xhosa },
+        Language { name: "yakut", iso_639_1: None, iso_639_3: "sah", config: config::yakut },
+        Language { name: "yiddish", iso_639_1: Some("yi"), iso_639_3: "yid", config: config::yiddish },
+        Language { name: "yoruba", iso_639_1: Some("yo"), iso_639_3: "yor", config: config::yoruba },
+        Language { name: "zaza", iso_639_1: None, iso_639_3: "zza", config: config::zaza },
+        Language { name: "zulu", iso_639_1: Some("zu"), iso_639_3: "zul", config: config::zulu },
+    ];
+
+    impl Language {
+        /// Segmenter with the default options, including the model for Thai.
+        pub fn segmenter(&self) -> Result<Segmenter, Error> {
+            #[cfg(feature = "thai")]
+            if self.name == "thai" {
+                return Segmenter::thai();
+            }
+            Segmenter::new((self.config)())
+        }
+
+        /// Segments `text` like the function of the same name in this module, reusing the
+        /// segmenter shared by those functions. Fails with [`Error::UnknownLanguage`] for a
+        /// language whose name is not in [`REGISTRY`].
+        pub fn segment(&self, text: &str) -> Result<Vec<String>, Error> {
+            let sentences = shared_segmenter(self.name)?.segment(text)?;
+            Ok(sentences.into_iter().map(|sentence| sentence.text).collect())
+        }
+    }
+
+    /// Looks a built-in language up by name, ISO 639-1 code or ISO 639-3 code, ignoring case.
+    pub fn language(name_or_code: &str) -> Option<&'static Language> {
+        let name_or_code = name_or_code.to_lowercase();
+        REGISTRY.iter().find(|language| {
+            language.name == name_or_code || language.iso_639_1 == Some(name_or_code.as_str()) || language.iso_639_3 == name_or_code
+        })
+    }
+
+    /// Segments documents in parallel with a built-in language, given by name or ISO 639 code,
+    /// reusing the segmenter shared with the functions of this module. The results are in the
+    /// order of `documents`.
+    #[cfg(feature = "rayon")]
+    pub fn segment_batch(lang: &str, documents: &[&str]) -> Result<Vec<Vec<Sentence>>, Error> {
+        let language = language(lang).ok_or_else(|| Error::UnknownLanguage(lang.to_string()))?;
+        shared_segmenter(language.name)?.segment_batch(documents)
     }
 }
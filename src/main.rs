@@ -0,0 +1,390 @@
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, ValueEnum};
+use sentence_segmentation::corpus::ConlluWriter;
+use sentence_segmentation::processor::{self, Cleaning, Language, Newlines, Segmenter, Sentence, Symbols, TrailingFragment};
+use sentence_segmentation::Error;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, BufWriter, Read, Write};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Splits text files or stdin into sentences.
+#[derive(Parser)]
+#[command(name = "sentence-segmentation", version)]
+struct Args {
+    /// Files or directories to segment, directories recursively. Reads stdin when empty or `-`.
+    paths: Vec<PathBuf>,
+    /// Language name, ISO 639-1 code or ISO 639-3 code.
+    #[arg(short, long, default_value = "english")]
+    lang: String,
+    /// Detect the language of each input, falling back to `--lang` when the detection is unreliable.
+    #[arg(long)]
+    auto_detect: bool,
+    /// Print the input text of each sentence instead of its cleaned text.
+    #[arg(long)]
+    keep_original: bool,
+    /// Input format.
+    #[arg(short, long, value_enum, default_value_t = Markup::Text)]
+    input: Markup,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Never let a sentence cross an empty line. Paragraphs are separated by an empty line in the text format
+    /// and numbered in the JSONL format. Only for `--input text`.
+    #[arg(long)]
+    paragraphs: bool,
+    /// Whether line breaks end sentences.
+    #[arg(long, value_enum, default_value_t = Lines::Ignore)]
+    newlines: Lines,
+    /// What to do with text after the last sentence terminator.
+    #[arg(long, value_enum, default_value_t = Trailing::AppendPeriod)]
+    trailing_fragment: Trailing,
+    /// Keep characters that are not letters of the language, digits or punctuation.
+    #[arg(long)]
+    keep_non_alphabet: bool,
+    /// Keep list numbers such as `1.`.
+    #[arg(long)]
+    keep_numbered_lists: bool,
+    /// Keep numbered references such as `[12]`.
+    #[arg(long)]
+    keep_references: bool,
+    /// Keep quotations and bracketed or parenthesised text.
+    #[arg(long)]
+    keep_quotations: bool,
+    /// Keep these symbols, separated by commas.
+    #[arg(long, value_enum, value_delimiter = ',')]
+    keep_symbols: Vec<SymbolKind>,
+    /// Number of inputs segmented at once. Defaults to the number of CPUs.
+    #[arg(short, long)]
+    jobs: Option<NonZeroUsize>,
+    /// Print the supported languages with their ISO 639-1 and 639-3 codes and exit.
+    #[arg(long)]
+    list_languages: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Markup {
+    /// Plain text.
+    Text,
+    /// Markdown, of which only the prose is segmented.
+    Markdown,
+    /// HTML, of which only the visible text is segmented.
+    Html,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One sentence per line.
+    Text,
+    /// One JSON object per sentence, with its byte span in the input.
+    Jsonl,
+    /// Source, span start, span end and sentence, separated by tabs.
+    Tsv,
+    /// CoNLL-U skeleton with one document per input and white-space tokens.
+    Conllu,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Lines {
+    /// Line breaks are white space.
+    Ignore,
+    /// Every line break ends a sentence.
+    Boundary,
+    /// A line break ends a sentence when the next line starts with a capital letter or a list marker.
+    Heuristic,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Trailing {
+    AppendPeriod,
+    Keep,
+    Drop,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SymbolKind {
+    Currency,
+    Math,
+    Percent,
+    Social,
+    Emoji,
+    All,
+}
+
+enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    fn name(&self) -> String {
+        match self {
+            Input::Stdin => "-".to_string(),
+            Input::File(path) => path.display().to_string(),
+        }
+    }
+
+    fn read(&self) -> io::Result<String> {
+        let mut text = String::new();
+        match self {
+            Input::Stdin => io::stdin().read_to_string(&mut text)?,
+            Input::File(path) => std::fs::File::open(path)?.read_to_string(&mut text)?,
+        };
+        Ok(text)
+    }
+}
+
+fn collect_inputs(paths: &[PathBuf]) -> io::Result<Vec<Input>> {
+    if paths.is_empty() {
+        return Ok(vec![Input::Stdin]);
+    }
+    let mut inputs = vec![];
+    for path in paths {
+        if path.as_os_str() == "-" {
+            inputs.push(Input::Stdin);
+        } else {
+            walk(path, &mut inputs)?;
+        }
+    }
+    Ok(inputs)
+}
+
+// directory entries are sorted so the output order does not depend on the file system, and
+// directories reached through symlinks are skipped
+fn walk(path: &Path, inputs: &mut Vec<Input>) -> io::Result<()> {
+    if !path.is_dir() {
+        inputs.push(Input::File(path.to_path_buf()));
+        return Ok(());
+    }
+    let mut entries = std::fs::read_dir(path)?.map(|entry| entry.map(|entry| entry.path())).collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for entry in entries {
+        // a symlinked directory may lead back to one of its ancestors
+        if entry.is_symlink() && entry.is_dir() {
+            continue;
+        }
+        walk(&entry, inputs)?;
+    }
+    Ok(())
+}
+
+// whatlang reports a few languages by a more specific ISO 639-3 code than the registry
+fn detect(text: &str, fallback: &'static Language) -> &'static Language {
+    let Some(info) = whatlang::detect(text).filter(|info| info.is_reliable()) else {
+        return fallback;
+    };
+    let code = match info.lang() {
+        whatlang::Lang::Cmn => "zho",
+        whatlang::Lang::Nob => "nor",
+        whatlang::Lang::Pes => "fas",
+        lang => lang.code(),
+    };
+    processor::language(code).unwrap_or(fallback)
+}
+
+// each worker compiles the segmenters it needs once and keeps them for its next inputs
+struct Worker<'a> {
+    args: &'a Args,
+    language: &'static Language,
+    segmenters: HashMap<&'static str, Segmenter>,
+}
+
+impl Worker<'_> {
+    fn segmenter(&mut self, language: &'static Language) -> Result<&Segmenter, Error> {
+        match self.segmenters.entry(language.name) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                let cleaning = Cleaning {
+                    non_alphabet: !self.args.keep_non_alphabet,
+                    numbered_lists: !self.args.keep_numbered_lists,
+                    references: !self.args.keep_references,
+                    quotations: !self.args.keep_quotations,
+                    symbols: symbols(&self.args.keep_symbols),
+                };
+                let trailing_fragment = match self.args.trailing_fragment {
+                    Trailing::AppendPeriod => TrailingFragment::AppendPeriod,
+                    Trailing::Keep => TrailingFragment::Keep,
+                    Trailing::Drop => TrailingFragment::Drop,
+                };
+                let newlines = match self.args.newlines {
+                    Lines::Ignore => Newlines::Ignore,
+                    Lines::Boundary => Newlines::Boundary,
+                    Lines::Heuristic => Newlines::Heuristic,
+                };
+                let segmenter = Segmenter::with_cleaning((language.config)(), cleaning)?
+                    .with_trailing_fragment(trailing_fragment)
+                    .with_newlines(newlines);
+                #[cfg(feature = "thai")]
+                let segmenter = if language.name == "thai" { segmenter.with_thai_model()? } else { segmenter };
+                Ok(entry.insert(segmenter))
+            }
+        }
+    }
+
+    fn run(&mut self, input: &Input) -> Result<String, String> {
+        let source = input.name();
+        let text = input.read().map_err(|error| format!("{}: {}", source, error))?;
+        let language = if self.args.auto_detect { detect(&text, self.language) } else { self.language };
+        let args = self.args;
+        let segmenter = self.segmenter(language).map_err(|error| format!("{}: {}", source, error))?;
+        let paragraphs = match args.input {
+            Markup::Text if args.paragraphs => segmenter.segment_paragraphs(&text).map(|paragraphs| paragraphs.into_iter().map(|paragraph| paragraph.sentences).collect()),
+            Markup::Text => segmenter.segment(&text).map(|sentences| vec![sentences]),
+            Markup::Markdown => segmenter.segment_markdown(&text).map(|sentences| vec![sentences]),
+            Markup::Html => segmenter.segment_html(&text).map(|sentences| vec![sentences]),
+        };
+        let paragraphs: Vec<Vec<Sentence>> = paragraphs.map_err(|error| format!("{}: {}", source, error))?;
+        if let Format::Conllu = args.format {
+            let sentences: Vec<Sentence> = paragraphs
+                .into_iter()
+                .flatten()
+                .map(|sentence| if args.keep_original { Sentence { text: text[sentence.span.clone()].to_string(), ..sentence } } else { sentence })
+                .collect();
+            let mut writer = ConlluWriter::new(vec![]);
+            writer.write_document(&source, &sentences).map_err(|error| format!("{}: {}", source, error))?;
+            return Ok(String::from_utf8(writer.into_inner()).expect("sentences are valid UTF-8"));
+        }
+        let mut output = String::new();
+        for (index, sentences) in paragraphs.iter().enumerate() {
+            if index > 0 && matches!(self.args.format, Format::Text) {
+                output.push('\n');
+            }
+            for sentence in sentences {
+                self.format(&mut output, &source, language, &text, index, sentence);
+            }
+        }
+        Ok(output)
+    }
+
+    fn format(&self, output: &mut String, source: &str, language: &Language, text: &str, paragraph: usize, sentence: &Sentence) {
+        let sentence_text = if self.args.keep_original { &text[sentence.span.clone()] } else { sentence.text.as_str() };
+        let line = match self.args.format {
+            Format::Text => sentence_text.replace(['\r', '\n'], " "),
+            Format::Jsonl => {
+                let mut line = serde_json::json!({
+                    "source": source,
+                    "language": language.name,
+                    "text": sentence_text,
+                    "start": sentence.span.start,
+                    "end": sentence.span.end,
+                    "terminator": sentence.terminator.map(String::from),
+                    "synthetic_terminator": sentence.synthetic_terminator,
+                });
+                if self.args.paragraphs {
+                    line["paragraph"] = paragraph.into();
+                }
+                line.to_string()
+            }
+            Format::Tsv => format!("{}\t{}\t{}\t{}", escape_tsv(source), sentence.span.start, sentence.span.end, escape_tsv(sentence_text)),
+            Format::Conllu => unreachable!("CoNLL-U documents are written whole"),
+        };
+        output.push_str(&line);
+        output.push('\n');
+    }
+}
+
+fn symbols(kinds: &[SymbolKind]) -> Symbols {
+    if kinds.contains(&SymbolKind::All) {
+        return Symbols::all();
+    }
+    Symbols {
+        currency: kinds.contains(&SymbolKind::Currency),
+        math: kinds.contains(&SymbolKind::Math),
+        percent: kinds.contains(&SymbolKind::Percent),
+        social: kinds.contains(&SymbolKind::Social),
+        emoji: kinds.contains(&SymbolKind::Emoji),
+    }
+}
+
+fn escape_tsv(field: &str) -> String {
+    field.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+// prints the results in input order as soon as all earlier inputs are done, and reports
+// whether every input was segmented
+fn write_in_order(results: mpsc::Receiver<(usize, Result<String, String>)>) -> io::Result<bool> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    let mut pending = BTreeMap::new();
+    let mut next = 0;
+    let mut succeeded = true;
+    for (index, result) in results {
+        pending.insert(index, result);
+        while let Some(result) = pending.remove(&next) {
+            match result {
+                Ok(output) => out.write_all(output.as_bytes())?,
+                Err(message) => {
+                    out.flush()?;
+                    eprintln!("sentence-segmentation: {}", message);
+                    succeeded = false;
+                }
+            }
+            next += 1;
+        }
+    }
+    out.flush()?;
+    Ok(succeeded)
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    if args.list_languages {
+        for language in processor::REGISTRY {
+            println!("{}\t{}\t{}", language.name, language.iso_639_1.unwrap_or("-"), language.iso_639_3);
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if args.paragraphs && !matches!(args.input, Markup::Text) {
+        Args::command().error(ErrorKind::ArgumentConflict, "--paragraphs only applies to `--input text`").exit();
+    }
+
+    let Some(language) = processor::language(&args.lang) else {
+        eprintln!("sentence-segmentation: unknown language {:?}, see --list-languages", args.lang);
+        return ExitCode::from(2);
+    };
+    let inputs = match collect_inputs(&args.paths) {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            eprintln!("sentence-segmentation: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let jobs = args.jobs.or_else(|| thread::available_parallelism().ok()).map_or(1, NonZeroUsize::get);
+    let next_input = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let written = thread::scope(|scope| {
+        for _ in 0..jobs.min(inputs.len()) {
+            let sender = sender.clone();
+            let (args, inputs, next_input) = (&args, &inputs, &next_input);
+            scope.spawn(move || {
+                let mut worker = Worker { args, language, segmenters: HashMap::new() };
+                loop {
+                    let index = next_input.fetch_add(1, Ordering::Relaxed);
+                    // stop early once the writer is gone, e.g. after a broken pipe
+                    if index >= inputs.len() || sender.send((index, worker.run(&inputs[index]))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+        write_in_order(receiver)
+    });
+
+    match written {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("sentence-segmentation: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
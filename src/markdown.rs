@@ -0,0 +1,62 @@
+use crate::tracked::Blocks;
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag, TagEnd};
+
+/// Collects the prose of a markdown document into blocks. Every block-level element starts
+/// a new block, so headings, list items and table cells never share a sentence.
+pub(crate) fn blocks(text: &str) -> Blocks {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut blocks = Blocks::default();
+    // depth of elements whose text is not prose, e.g. code blocks and image descriptions
+    let mut hidden = 0;
+    let mut in_autolink = false;
+
+    for (event, range) in Parser::new_ext(text, options).into_offset_iter() {
+        match event {
+            Event::Start(tag) => {
+                if is_block(&tag) {
+                    blocks.end_block();
+                }
+                match tag {
+                    Tag::CodeBlock(_) | Tag::HtmlBlock | Tag::MetadataBlock(_) | Tag::Image { .. } => hidden += 1,
+                    Tag::Link { link_type: LinkType::Autolink | LinkType::Email, .. } => in_autolink = true,
+                    _ => {}
+                }
+            }
+            Event::End(tag) => {
+                match tag {
+                    TagEnd::CodeBlock | TagEnd::HtmlBlock | TagEnd::MetadataBlock(_) | TagEnd::Image => hidden -= 1,
+                    TagEnd::Link => in_autolink = false,
+                    _ => {}
+                }
+                if is_block_end(&tag) {
+                    blocks.end_block();
+                }
+            }
+            _ if hidden > 0 => {}
+            Event::Text(content) if in_autolink => blocks.protect(&content, range),
+            // escapes and entities make the text differ from its source
+            Event::Text(content) if text[range.clone()] == *content => blocks.push_input(&content, range.start),
+            Event::Text(content) => blocks.push_str(&content, range),
+            Event::Code(_) | Event::InlineMath(_) | Event::DisplayMath(_) => blocks.protect(&text[range.clone()], range),
+            Event::SoftBreak | Event::HardBreak => blocks.push_str(" ", range),
+            Event::Rule => blocks.end_block(),
+            Event::Html(_) | Event::InlineHtml(_) | Event::FootnoteReference(_) | Event::TaskListMarker(_) => {}
+        }
+    }
+    blocks.end_block();
+    blocks
+}
+
+fn is_block(tag: &Tag) -> bool {
+    !matches!(
+        tag,
+        Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Superscript | Tag::Subscript | Tag::Link { .. } | Tag::Image { .. }
+    )
+}
+
+fn is_block_end(tag: &TagEnd) -> bool {
+    !matches!(
+        tag,
+        TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Superscript | TagEnd::Subscript | TagEnd::Link | TagEnd::Image
+    )
+}
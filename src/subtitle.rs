@@ -0,0 +1,133 @@
+use crate::error::Error;
+use crate::html::{entity, markup_end};
+use crate::tracked::Blocks;
+use std::ops::Range;
+use std::time::Duration;
+
+/// A cue of a subtitle file: its timing, the byte range of its text in the file and the
+/// offsets of its visible characters.
+pub(crate) struct Cue {
+    pub(crate) start: Duration,
+    pub(crate) end: Duration,
+    pub(crate) text: Range<usize>,
+    characters: Vec<usize>,
+}
+
+pub(crate) struct Subtitles {
+    pub(crate) blocks: Blocks,
+    pub(crate) cues: Vec<Cue>,
+}
+
+/// Parses SRT or WebVTT and joins the text of all cues into one block, without cue indices,
+/// headers, notes, formatting tags and positioning tags.
+pub(crate) fn parse(source: &str) -> Result<Subtitles, Error> {
+    let mut blocks = Blocks::default();
+    let mut cues: Vec<Cue> = vec![];
+    // whether the lines that follow are the text of the last cue
+    let mut in_cue = false;
+    let mut position = 0;
+
+    let mut lines = source.split_inclusive('\n').enumerate().peekable();
+    while let Some((number, line)) = lines.next() {
+        let start = position;
+        position += line.len();
+        let content = line.trim_end_matches(['\r', '\n']);
+        if content.trim().is_empty() {
+            in_cue = false;
+        } else if let Some(arrow) = content.find("-->") {
+            let (cue_start, cue_end) = parse_timing(content, arrow)
+                .ok_or_else(|| Error::InvalidSubtitle { line: number + 1, message: format!("invalid cue timing {:?}", content) })?;
+            cues.push(Cue { start: cue_start, end: cue_end, text: position..position, characters: vec![] });
+            in_cue = true;
+        } else if is_number(content.trim()) && lines.peek().is_some_and(|(_, next)| next.contains("-->")) {
+            // the index of the next cue, also when no blank line ended the last one
+            in_cue = false;
+        } else if in_cue {
+            let cue = cues.last_mut().expect("a cue precedes its text");
+            if cue.text.is_empty() {
+                cue.text.start = start;
+            }
+            cue.text.end = start + content.len();
+            // lines and cues are joined by a space attributed to the preceding line break
+            if start > 0 {
+                blocks.push_str(" ", start - 1..start);
+            }
+            push_line(&mut blocks, &mut cue.characters, content, start);
+        }
+    }
+    blocks.end_block();
+    Ok(Subtitles { blocks, cues })
+}
+
+/// Time at byte `offset` of the source, interpolated by visible characters within the cue
+/// that holds it.
+pub(crate) fn time_at(cues: &[Cue], offset: usize) -> Duration {
+    let index = cues.partition_point(|cue| cue.text.start <= offset).saturating_sub(1);
+    let Some(cue) = cues.get(index) else {
+        return Duration::ZERO;
+    };
+    if cue.characters.is_empty() {
+        return cue.start;
+    }
+    let elapsed = cue.characters.partition_point(|&character| character < offset);
+    cue.start + cue.end.saturating_sub(cue.start).mul_f64(elapsed as f64 / cue.characters.len() as f64)
+}
+
+// drops `<i>`, `<v Speaker>`, `<00:00:01.500>` and `{\an8}` tags and decodes entities
+fn push_line(blocks: &mut Blocks, characters: &mut Vec<usize>, line: &str, start: usize) {
+    let mut last = 0;
+    let mut index = 0;
+    while let Some(offset) = line[index..].find(['<', '{', '&']) {
+        let at = index + offset;
+        let rest = &line[at..];
+        let skipped = if rest.starts_with('<') {
+            markup_end(rest, b'>').map(|end| (end + 1, None))
+        } else if rest.starts_with("{\\") {
+            markup_end(rest, b'}').map(|end| (end + 1, None))
+        } else if rest.starts_with('&') {
+            entity(rest).map(|(decoded, length)| (length, Some(decoded)))
+        } else {
+            None
+        };
+        match skipped {
+            Some((length, decoded)) => {
+                push_visible(blocks, characters, &line[last..at], start + last);
+                if let Some(decoded) = decoded {
+                    blocks.push_str(&decoded, start + at..start + at + length);
+                    characters.push(start + at);
+                }
+                index = at + length;
+                last = index;
+            }
+            None => index = at + 1,
+        }
+    }
+    push_visible(blocks, characters, &line[last..], start + last);
+}
+
+fn push_visible(blocks: &mut Blocks, characters: &mut Vec<usize>, text: &str, start: usize) {
+    blocks.push_input(text, start);
+    characters.extend(text.char_indices().map(|(index, _)| start + index));
+}
+
+// the end timestamp may be followed by WebVTT cue settings
+fn parse_timing(line: &str, arrow: usize) -> Option<(Duration, Duration)> {
+    let start = timestamp(line[..arrow].trim())?;
+    let end = timestamp(line[arrow + 3..].split_whitespace().next()?)?;
+    Some((start, end))
+}
+
+// `hh:mm:ss,mmm` in SRT, `[hh:]mm:ss.mmm` in WebVTT
+fn timestamp(text: &str) -> Option<Duration> {
+    let (clock, millis) = text.split_once([',', '.'])?;
+    let fields: Vec<&str> = clock.split(':').collect();
+    if !(2..=3).contains(&fields.len()) || millis.len() != 3 || !fields.iter().chain([&millis]).all(|field| is_number(field)) {
+        return None;
+    }
+    let seconds = fields.iter().fold(0u64, |seconds, field| seconds * 60 + field.parse::<u64>().unwrap_or(0));
+    Some(Duration::from_millis(seconds * 1000 + millis.parse::<u64>().ok()?))
+}
+
+fn is_number(field: &str) -> bool {
+    !field.is_empty() && field.bytes().all(|byte| byte.is_ascii_digit())
+}
@@ -0,0 +1,168 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// Text rewritten by the segmentation rules. Every byte remembers the byte range of the
+/// original input it was produced from, so sentences can be mapped back to the input.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TrackedText {
+    text: String,
+    origins: Vec<(usize, usize)>,
+}
+
+impl TrackedText {
+    pub(crate) fn new(text: &str) -> Self {
+        let mut origins = Vec::with_capacity(text.len());
+        for (index, ch) in text.char_indices() {
+            origins.extend(std::iter::repeat_n((index, index + ch.len_utf8()), ch.len_utf8()));
+        }
+        TrackedText { text: text.to_string(), origins }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Byte range of the input covered by the text, from its first to its last byte.
+    pub(crate) fn span(&self) -> Range<usize> {
+        match (self.origins.first(), self.origins.last()) {
+            (Some(first), Some(last)) => first.0..last.1,
+            _ => 0..0,
+        }
+    }
+
+    /// Appends a slice of the input that starts at byte `start` of the input.
+    pub(crate) fn push_input(&mut self, text: &str, start: usize) {
+        self.text.push_str(text);
+        for (index, ch) in text.char_indices() {
+            let origin = (start + index, start + index + ch.len_utf8());
+            self.origins.extend(std::iter::repeat_n(origin, ch.len_utf8()));
+        }
+    }
+
+    /// Appends text that was produced from `origin` of the input.
+    pub(crate) fn push_str(&mut self, text: &str, origin: Range<usize>) {
+        self.text.push_str(text);
+        self.origins.extend(std::iter::repeat_n((origin.start, origin.end), text.len()));
+    }
+
+    /// Whether the bytes on both sides of `index` were next to each other in the input, i.e. no
+    /// character between them was removed.
+    pub(crate) fn adjacent_at(&self, index: usize) -> bool {
+        match (index.checked_sub(1).and_then(|before| self.origins.get(before)), self.origins.get(index)) {
+            (Some(before), Some(after)) => before.1 == after.0,
+            _ => false,
+        }
+    }
+
+    pub(crate) fn push_slice(&mut self, other: &TrackedText, range: Range<usize>) {
+        self.text.push_str(&other.text[range.clone()]);
+        self.origins.extend_from_slice(&other.origins[range]);
+    }
+
+    pub(crate) fn slice(&self, range: Range<usize>) -> TrackedText {
+        TrackedText { text: self.text[range.clone()].to_string(), origins: self.origins[range].to_vec() }
+    }
+
+    pub(crate) fn trim(&self) -> TrackedText {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+        self.slice(start..end)
+    }
+
+    /// Replaces non-overlapping byte ranges, given in order. A replacement is attributed to
+    /// the whole input range of the text it replaces.
+    pub(crate) fn replace(&mut self, edits: Vec<(Range<usize>, Cow<'_, str>)>) {
+        if edits.is_empty() {
+            return;
+        }
+        let mut replaced = TrackedText::default();
+        let mut last = 0;
+        for (range, replacement) in edits {
+            replaced.push_slice(self, last..range.start);
+            let origin = self.origin_of(range.clone());
+            replaced.push_str(&replacement, origin);
+            last = range.end;
+        }
+        replaced.push_slice(self, last..self.text.len());
+        *self = replaced;
+    }
+
+    pub(crate) fn replace_literal(&mut self, from: &str, to: &str) {
+        let edits = self.text.match_indices(from).map(|(index, _)| (index..index + from.len(), Cow::Borrowed(to))).collect();
+        self.replace(edits);
+    }
+
+    // an empty range is attributed to the empty input range where it sits
+    fn origin_of(&self, range: Range<usize>) -> Range<usize> {
+        if range.is_empty() {
+            let position = match self.origins.get(range.start) {
+                Some(origin) => origin.0,
+                None => self.origins.last().map_or(0, |origin| origin.1),
+            };
+            position..position
+        } else {
+            self.origins[range.start].0..self.origins[range.end - 1].1
+        }
+    }
+}
+
+// protected tokens are replaced by supplementary private use characters, which step 2 keeps
+// and no other rule matches
+const PLACEHOLDER_START: u32 = 0xF0000;
+const PLACEHOLDER_END: u32 = 0xFFFFD;
+
+pub(crate) fn placeholder_index(ch: char) -> Option<usize> {
+    (PLACEHOLDER_START..=PLACEHOLDER_END).contains(&(ch as u32)).then(|| (ch as u32 - PLACEHOLDER_START) as usize)
+}
+
+/// Placeholder of the token at `index`, if there are enough placeholders.
+pub(crate) fn placeholder(index: usize) -> Option<char> {
+    u32::try_from(index).ok()
+        .and_then(|index| PLACEHOLDER_START.checked_add(index))
+        .filter(|&code| code <= PLACEHOLDER_END)
+        .and_then(char::from_u32)
+}
+
+/// Blocks of text extracted by a front end such as the markdown or HTML parser. Sentences never
+/// cross blocks. Protected tokens, e.g. inline code, are replaced in the blocks by
+/// placeholders that the rules leave alone, and are restored in the sentences.
+#[derive(Default)]
+pub(crate) struct Blocks {
+    pub(crate) blocks: Vec<TrackedText>,
+    pub(crate) tokens: Vec<String>,
+    current: TrackedText,
+}
+
+impl Blocks {
+    pub(crate) fn push_input(&mut self, text: &str, start: usize) {
+        self.current.push_input(text, start);
+    }
+
+    pub(crate) fn push_str(&mut self, text: &str, origin: Range<usize>) {
+        self.current.push_str(text, origin);
+    }
+
+    /// Appends a token that must come out of segmentation unchanged. Past the last
+    /// placeholder the token is appended as plain text.
+    #[cfg(feature = "markdown")]
+    pub(crate) fn protect(&mut self, token: &str, origin: Range<usize>) {
+        match placeholder(self.tokens.len()) {
+            Some(placeholder) => {
+                self.current.push_str(placeholder.encode_utf8(&mut [0; 4]), origin);
+                self.tokens.push(token.to_string());
+            }
+            None => self.current.push_str(token, origin),
+        }
+    }
+
+    pub(crate) fn end_block(&mut self) {
+        let block = std::mem::take(&mut self.current);
+        if !block.as_str().trim().is_empty() {
+            self.blocks.push(block);
+        }
+    }
+}
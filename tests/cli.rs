@@ -0,0 +1,55 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn run(args: &[&str], input: &str) -> (String, bool) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sentence-segmentation"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    // the binary may exit on bad arguments before reading its input
+    child.stdin.take().unwrap().write_all(input.as_bytes()).ok();
+    let output = child.wait_with_output().unwrap();
+    (String::from_utf8(output.stdout).unwrap(), output.status.success())
+}
+
+#[test]
+fn test_cli() {
+    let text = "Is it done? Almost";
+    assert_eq!(run(&["--lang", "en"], text), ("Is it done?\nAlmost.\n".to_string(), true));
+    assert_eq!(run(&["-l", "eng", "--trailing-fragment", "keep", "-f", "tsv"], text), ("-\t0\t11\tIs it done?\n-\t12\t18\tAlmost\n".to_string(), true));
+
+    let (jsonl, succeeded) = run(&["-f", "jsonl"], text);
+    assert!(succeeded);
+    let first: serde_json::Value = serde_json::from_str(jsonl.lines().next().unwrap()).unwrap();
+    assert_eq!(first["terminator"], "?");
+    assert_eq!(first["end"], 11);
+
+    assert_eq!(run(&["-i", "html"], "<p>First</p><p>Second one.</p>"), ("First.\nSecond one.\n".to_string(), true));
+
+    assert_eq!(run(&["--keep-symbols", "currency,percent"], "Only $5 for 50% off."), ("Only $5 for 50% off.\n".to_string(), true));
+
+    let (conllu, succeeded) = run(&["-f", "conllu"], text);
+    assert!(succeeded);
+    assert!(conllu.starts_with("# newdoc id = -\n# sent_id = --1\n# text = Is it done?\n1\tIs\t_"));
+
+    assert!(!run(&["--lang", "xx"], text).1);
+    assert!(!run(&["--paragraphs", "-i", "html"], "<p>First.</p>").1);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_cli_skips_symlinked_directories() {
+    let directory = std::env::temp_dir().join(format!("sentence-segmentation-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(directory.join("a.txt"), "One here. Two here.").unwrap();
+    // a loop back to the directory itself
+    std::os::unix::fs::symlink(&directory, directory.join("loop")).unwrap();
+    let result = run(&[directory.to_str().unwrap()], "");
+    std::fs::remove_dir_all(&directory).unwrap();
+    assert_eq!(result, ("One here.\nTwo here.\n".to_string(), true));
+}
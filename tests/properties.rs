@@ -0,0 +1,89 @@
+use proptest::prelude::*;
+use proptest::sample::select;
+use sentence_segmentation::processor;
+use sentence_segmentation::processor::{config, LanguageConfig, Segmenter};
+
+const MASKS: [&str; 4] = ["&^&", "&ᓴ&", "&ᓷ&", "&ᓵ&"];
+
+// Plain text, text dense in the characters the rules react to, and arbitrary unicode.
+fn text() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-zA-Z ]{0,60}[.?!]?",
+        "([A-Za-z]{1,8}|[0-9]{1,4}|[.?!,:;'\"«»“”‘’„‚()\\[\\]「」『』。？！-]|\\s){0,40}",
+        any::<String>(),
+    ]
+}
+
+// Well-formed sentences: a capitalised word, a few lowercase words or numbers, and an
+// optional terminator.
+fn prose() -> impl Strategy<Value = String> {
+    prop::collection::vec("[A-Z][a-z]{0,7}(,? ([a-z]{1,8}|[0-9]{1,4})){0,8}[.?!]?", 1..5)
+        .prop_map(|sentences| sentences.join(" "))
+}
+
+// Markup with long runs of the characters that start entities, tags and subtitle tags.
+fn markup() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop_oneof!["&{1,3000}", "<{1,3000}", "(<a ){1,1000}", "(\\{\\\\){1,1000}", "&#?[a-z0-9]{0,40};?", "[a-z .]{0,20}"], 1..6)
+        .prop_map(|parts| parts.concat())
+}
+
+fn language() -> impl Strategy<Value = &'static processor::Language> {
+    select(processor::REGISTRY.iter().collect::<Vec<_>>())
+}
+
+fn configuration() -> impl Strategy<Value = fn() -> LanguageConfig> {
+    select(vec![config::english as fn() -> LanguageConfig, config::japanese, config::arabic])
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn segmentation_never_panics(language in language(), text in text()) {
+        language.segment(&text).unwrap();
+    }
+
+    #[test]
+    fn sentences_are_trimmed_and_unmasked(language in language(), text in text()) {
+        let name = language.name;
+        for sentence in language.segment(&text).unwrap() {
+            prop_assert!(std::str::from_utf8(sentence.as_bytes()).is_ok());
+            prop_assert!(!sentence.is_empty(), "{}: empty sentence", name);
+            prop_assert_eq!(sentence.trim(), sentence.as_str(), "{}: untrimmed sentence", name);
+            for mask in MASKS {
+                prop_assert!(!sentence.contains(mask), "{}: {:?} leaked into {:?}", name, mask, sentence);
+            }
+        }
+    }
+
+    #[test]
+    fn segmentation_is_idempotent(language in language(), text in prose()) {
+        let sentences = language.segment(&text).unwrap();
+        let resegmented: Vec<String> = sentences.iter().flat_map(|sentence| language.segment(sentence).unwrap()).collect();
+        prop_assert_eq!(&resegmented, &sentences, "{}: resegmenting {:?}", language.name, text);
+    }
+
+    #[test]
+    fn markup_with_long_runs_is_segmented(markup in markup()) {
+        let segmenter = Segmenter::new(config::english()).unwrap();
+        for sentence in segmenter.segment_html(&markup).unwrap() {
+            prop_assert!(sentence.span.end <= markup.len(), "{:?} in {:?}", sentence, markup);
+        }
+        let subtitles = format!("1\n00:00:01,000 --> 00:00:02,000\n{}\n", markup);
+        for timed in segmenter.segment_subtitles(&subtitles).unwrap() {
+            prop_assert!(timed.sentence.span.end <= subtitles.len(), "{:?} in {:?}", timed.sentence, subtitles);
+        }
+    }
+
+    #[test]
+    fn spans_are_ordered_and_in_bounds(config in configuration(), text in text()) {
+        let segmenter = Segmenter::new(config()).unwrap();
+        let mut previous_end = 0;
+        for sentence in segmenter.segment(&text).unwrap() {
+            let span = sentence.span.clone();
+            prop_assert!(previous_end <= span.start && span.start < span.end && span.end <= text.len(), "{:?} in {:?}", sentence, text);
+            prop_assert!(text.is_char_boundary(span.start) && text.is_char_boundary(span.end));
+            previous_end = span.end;
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use sentence_segmentation::processor;
+use sentence_segmentation::corpus::{ConlluWriter, LineWriter};
+use sentence_segmentation::{processor, Error};
+use std::time::Duration;
 
 #[test]
 fn test_sentence_segmentation() {
@@ -880,4 +882,471 @@ fn test_sentence_segmentation() {
     for sentence in sentences {
         println!("{}", sentence);
     }
-}
\ No newline at end of file
+}
+#[test]
+fn test_segmenter() {
+    let text = "How are you? I am fine. See you tomorrow!";
+    let segmenter = processor::Segmenter::new(processor::config::english()).unwrap();
+    let texts: Vec<String> = segmenter.segment(text).unwrap().into_iter().map(|sentence| sentence.text).collect();
+    assert_eq!(texts, processor::english(text));
+
+    // private use characters are stripped from plain text as before
+    assert_eq!(processor::english("Hello \u{F0000}world."), vec!["Hello world."]);
+
+    let mut config = processor::config::english();
+    config.alphabets = "a-z]|(".to_string();
+    assert!(matches!(processor::Segmenter::new(config), Err(Error::InvalidAlphabet { .. })));
+
+    let mut config = processor::config::english();
+    config.alphabets = "\\p{NotAScript}".to_string();
+    assert!(matches!(processor::Segmenter::new(config), Err(Error::InvalidAlphabet { .. })));
+
+    let mut config = processor::config::english();
+    config.period = "..".to_string();
+    assert_eq!(processor::Segmenter::new(config).err(), Some(Error::InvalidPunctuation("..".to_string())));
+
+    let mut config = processor::config::japanese();
+    config.quotes.pairs.push("「".to_string());
+    assert_eq!(processor::Segmenter::new(config).err(), Some(Error::InvalidQuotes("「".to_string())));
+}
+
+#[test]
+fn test_sentence_metadata() {
+    let segmenter = processor::Segmenter::new(processor::config::english()).unwrap();
+
+    let text = "Are you   coming? Bring the dog. and the cat.  See you soon";
+    let sentences = segmenter.segment(text).unwrap();
+    let summary: Vec<(&str, &str, Option<char>, bool, bool)> = sentences.iter()
+        .map(|sentence| (sentence.text.as_str(), &text[sentence.span.clone()], sentence.terminator, sentence.synthetic_terminator, sentence.merged))
+        .collect();
+    assert_eq!(summary, vec![
+        ("Are you coming?", "Are you   coming?", Some('?'), false, false),
+        ("Bring the dog. and the cat.", "Bring the dog. and the cat.", Some('.'), false, true),
+        ("See you soon.", "See you soon", Some('.'), true, false),
+    ]);
+    assert_eq!(sentences[0].to_string(), "Are you coming?");
+
+    // the span covers text the cleaning rules removed from the sentence
+    let text = "He said (quietly) nothing.";
+    let sentences = segmenter.segment(text).unwrap();
+    assert_eq!(sentences[0].text, "He said nothing.");
+    assert_eq!(sentences[0].span, 0..text.len());
+}
+
+#[test]
+fn test_trailing_fragment() {
+    use processor::TrailingFragment;
+
+    let text = "Read the guide. Installation";
+    let segment = |policy: TrailingFragment| {
+        let segmenter = processor::Segmenter::new(processor::config::english()).unwrap().with_trailing_fragment(policy);
+        segmenter.segment(text).unwrap()
+    };
+
+    let sentences = segment(TrailingFragment::AppendPeriod);
+    assert_eq!(sentences[1].text, "Installation.");
+    assert_eq!(sentences[1].trailing_fragment, Some(TrailingFragment::AppendPeriod));
+    assert!(sentences[1].synthetic_terminator);
+    assert_eq!(sentences[0].trailing_fragment, None);
+
+    let sentences = segment(TrailingFragment::Keep);
+    assert_eq!(sentences[1].text, "Installation");
+    assert_eq!(sentences[1].terminator, None);
+    assert_eq!(sentences[1].trailing_fragment, Some(TrailingFragment::Keep));
+    assert!(!sentences[1].synthetic_terminator);
+
+    let sentences = segment(TrailingFragment::Drop);
+    let texts: Vec<&str> = sentences.iter().map(|sentence| sentence.text.as_str()).collect();
+    assert_eq!(texts, vec!["Read the guide."]);
+}
+
+#[test]
+fn test_language_registry() {
+    for query in ["english", "EN", "eng"] {
+        assert_eq!(processor::language(query).map(|language| language.name), Some("english"));
+    }
+    assert_eq!(processor::language("pnb").map(|language| language.name), Some("punjabi_western"));
+    assert_eq!(processor::language("mn").map(|language| language.name), Some("mongolian"));
+    assert_eq!(processor::language("mvf").map(|language| language.name), Some("mongolian_traditional"));
+    // Scots, not Scottish Gaelic
+    assert_eq!(processor::language("sco").map(|language| language.name), Some("scottish"));
+    assert!(processor::language("gla").is_none());
+    assert!(processor::language("xx").is_none());
+    assert_eq!(processor::language("de").unwrap().segment("Er kam. Sie ging.").unwrap(), processor::german("Er kam. Sie ging."));
+    let custom = processor::Language { name: "klingon", iso_639_1: None, iso_639_3: "tlh", config: processor::config::english };
+    assert_eq!(custom.segment("Qapla'."), Err(Error::UnknownLanguage("klingon".to_string())));
+
+    // the segmenters of the built-in languages are compiled once and shared between threads
+    let handles: Vec<_> = (0..4).map(|_| std::thread::spawn(|| processor::english("One here. Two here."))).collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), vec!["One here.", "Two here."]);
+    }
+}
+
+#[test]
+fn test_alphabets() {
+    assert_eq!(processor::english("Zoë Müller ordered a café au lait. It was naïve to add jalapeño!"), vec!["Zoë Müller ordered a café au lait.", "It was naïve to add jalapeño!"]);
+    assert_eq!(processor::spanish("El pingüino duerme."), vec!["El pingüino duerme."]);
+    // CJK Extension A and B, and a decomposed é
+    assert_eq!(processor::chinese("㐀𠀀字。"), vec!["㐀𠀀字。"]);
+    assert_eq!(processor::french("Le cafe\u{0301} est chaud."), vec!["Le cafe\u{0301} est chaud."]);
+    assert_eq!(processor::guarani("Mbaʼéichapa."), vec!["Mbaʼéichapa."]);
+    assert_eq!(processor::mongolian("Сайн уу! Би сайн."), vec!["Сайн уу!", "Би сайн."]);
+
+    let mut custom = processor::config::english();
+    custom.alphabets = processor::config::scripts(&["Latin"], "");
+    assert_eq!(custom.alphabets, r"\p{scx=Latin}\p{M}");
+    custom.alphabets = "a-z".to_string();
+    let segmenter = processor::Segmenter::new(custom).unwrap();
+    assert_eq!(segmenter.segment("café ok.").unwrap()[0].text, "caf ok.");
+}
+
+#[test]
+fn test_protected_tokens() {
+    let text = "Read https://example.com/docs/a_b?x=1&y=2#top. Mail john.doe+news@example.co.uk, then see example.com. Logs are in /var/log/app.log and C:\\Temp\\out.txt! The server 192.168.0.1:8080 is up. See (https://en.wikipedia.org/wiki/Rust_(programming_language)).";
+    assert_eq!(processor::english(text), vec![
+        "Read https://example.com/docs/a_b?x=1&y=2#top.",
+        "Mail john.doe+news@example.co.uk then see example.com.",
+        "Logs are in /var/log/app.log and C:\\Temp\\out.txt!",
+        "The server 192.168.0.1:8080 is up.",
+        "See (https://en.wikipedia.org/wiki/Rust_(programming_language)).",
+    ]);
+    // bracket removal keeps a group holding only a protected token, but not one with other words
+    assert_eq!(processor::english("See (https://example.com/a). Also [mail@example.com] and (the site example.com) here."), vec![
+        "See (https://example.com/a).",
+        "Also [mail@example.com] and here.",
+    ]);
+    let cleaning = processor::Cleaning { quotations: false, ..Default::default() };
+    let segmenter = processor::Segmenter::with_cleaning(processor::config::english(), cleaning).unwrap();
+    let sentences = segmenter.segment("See (https://en.wikipedia.org/wiki/Rust_(programming_language)). Done.").unwrap();
+    assert_eq!(sentences[0].text, "See (https://en.wikipedia.org/wiki/Rust_(programming_language)).");
+    assert_eq!(sentences[0].span, 0..64);
+    assert_eq!(processor::japanese("詳細はhttps://example.jp/a。次の文。"), vec!["詳細はhttps://example.jp/a。", "次の文。"]);
+}
+
+#[test]
+fn test_numbers() {
+    // decimal digits of other scripts are kept like ASCII digits
+    assert_eq!(processor::english("It rained in २०२४ and in ２０２５."), vec!["It rained in २०२४ and in ２０２５."]);
+    let text = "Pi is about 3.14. Update to v1.2.3 by 12.05.2024 at 10.30 am. It grew to 1,250,000 users at 09:45. It ended in 2019. Then it restarted.";
+    assert_eq!(processor::english(text), vec![
+        "Pi is about 3.14.",
+        "Update to v1.2.3 by 12.05.2024 at 10.30 am.",
+        "It grew to 1,250,000 users at 09:45.",
+        "It ended in 2019.",
+        "Then it restarted.",
+    ]);
+    assert_eq!(processor::english("Steps. 1. Open the box. 2. Take it out."), vec!["Steps.", "Open the box.", "Take it out."]);
+    assert_eq!(processor::english("Read this first\n1. Open the box.\n2. Take it out."), vec!["Read this first Open the box.", "Take it out."]);
+    // a number ending a sentence is not a list item
+    assert_eq!(processor::english("The price rose to 100. Then it fell."), vec!["The price rose to 100.", "Then it fell."]);
+}
+
+#[test]
+fn test_ordinals() {
+    let text = "Am 3. Oktober feiern wir die Einheit. Der 2. Weltkrieg endete 1945. Heute ist ein schöner Tag.";
+    assert_eq!(processor::german(text), vec!["Am 3. Oktober feiern wir die Einheit.", "Der 2. Weltkrieg endete 1945.", "Heute ist ein schöner Tag."]);
+    assert_eq!(processor::hungarian("1848. március 15 nagy nap volt. A 2. világháború véget ért."), vec!["1848. március 15 nagy nap volt.", "A 2. világháború véget ért."]);
+    assert_eq!(processor::czech("Narodil se 28. října 1918. Byl to 2. pokus."), vec!["Narodil se 28. října 1918.", "Byl to 2. pokus."]);
+
+    // after an article, when a lowercase word or a listed word follows
+    assert_eq!(processor::german("Die 2. große Staffel beginnt. Wir freuen uns."), vec!["Die 2. große Staffel beginnt.", "Wir freuen uns."]);
+    assert_eq!(processor::german("Das ist der 3. Platz. Gut."), vec!["Das ist der 3. Platz.", "Gut."]);
+    assert_eq!(processor::hungarian("Elkezdődött a 3. évad."), vec!["Elkezdődött a 3. évad."]);
+    // while a capitalised word after the number may start the next sentence
+    assert_eq!(processor::german("Das ist die 3. Sie ist gut."), vec!["Das ist die 3.", "Sie ist gut."]);
+
+    let mut german = processor::config::german();
+    german.ordinals.nouns.push("Tor".to_string());
+    let sentences = processor::Segmenter::new(german).unwrap().segment("Er schoss sein 2. Tor. Wir jubelten.").unwrap();
+    assert_eq!(sentences[0].text, "Er schoss sein 2. Tor.");
+}
+
+#[test]
+fn test_greek_question_marks() {
+    assert_eq!(processor::greek("Τι κάνεις; Είμαι καλά. Πού πας\u{037E} Στο σπίτι."), vec!["Τι κάνεις;", "Είμαι καλά.", "Πού πας\u{037E}", "Στο σπίτι."]);
+    assert_eq!(processor::greek("Έφερε ψωμί· μετά έφυγε. Πότε έρχεσαι;"), vec!["Έφερε ψωμί· μετά έφυγε.", "Πότε έρχεσαι;"]);
+    assert_eq!(processor::greek("Έγραψε Apple; Google και Microsoft. Καλά."), vec!["Έγραψε Apple; Google και Microsoft.", "Καλά."]);
+    assert_eq!(processor::greek("Γράψε x; y για τη λίστα. Τέλος."), vec!["Γράψε x; y για τη λίστα.", "Τέλος."]);
+    // the ano teleia and its NFC form, the middle dot, never end a sentence
+    assert_eq!(processor::greek("Έφερε ψωμί\u{0387} μετά έφυγε."), vec!["Έφερε ψωμί\u{0387} μετά έφυγε."]);
+    assert_eq!(processor::greek("Έφερε ψωμί\u{00B7} μετά έφυγε."), vec!["Έφερε ψωμί\u{00B7} μετά έφυγε."]);
+    let sentences = processor::Segmenter::new(processor::config::greek()).unwrap().segment("Πού πας; Στο σπίτι.").unwrap();
+    assert_eq!(sentences[0].terminator, Some(';'));
+    assert_eq!(processor::english("Bring bread; then leave. Done."), vec!["Bring bread then leave.", "Done."]);
+}
+
+#[test]
+fn test_inverted_marks() {
+    let segmenter = processor::Segmenter::new(processor::config::spanish()).unwrap();
+    let sentences = segmenter.segment("¿Vienes? dijo ella. Si llueve, ¿vienes? Claro que sí.").unwrap();
+    assert_eq!(sentences.iter().map(|sentence| sentence.text.as_str()).collect::<Vec<_>>(), vec!["¿Vienes? dijo ella.", "Si llueve ¿vienes?", "Claro que sí."]);
+    assert!(!sentences[0].merged);
+    assert_eq!(sentences[1].terminator, Some('?'));
+
+    assert_eq!(processor::spanish("Preguntó ¿Vienes?, y se fue. ¡Qué bien! ¿Y tú?"), vec!["Preguntó ¿Vienes? y se fue.", "¡Qué bien!", "¿Y tú?"]);
+    assert_eq!(processor::spanish("¿Sabes que ¡ganamos! ayer? Sí."), vec!["¿Sabes que ¡ganamos! ayer?", "Sí."]);
+    assert_eq!(processor::galician("¿Vés? Pedro dixo que si."), vec!["¿Vés?", "Pedro dixo que si."]);
+
+    let mut english = processor::config::english();
+    english.inverted_marks = true;
+    let sentences = processor::Segmenter::new(english).unwrap().segment("Ask ¿Qué? said Ann. Fine.").unwrap();
+    assert_eq!(sentences[0].text, "Ask ¿Qué? said Ann.");
+}
+
+#[test]
+fn test_quoted_speech() {
+    assert_eq!(processor::japanese("「行きます。」と言った。彼は帰った。"), vec!["「行きます。」と言った。", "彼は帰った。"]);
+    assert_eq!(processor::japanese("彼は「行きます。また来ます。」と言って出かけた。"), vec!["彼は「行きます。また来ます。」と言って出かけた。"]);
+    assert_eq!(processor::japanese("「元気？」「元気だよ！」次の日に会った。"), vec!["「元気？」", "「元気だよ！」", "次の日に会った。"]);
+    assert_eq!(processor::chinese("“你好。”他说。我们走吧！"), vec!["“你好。”他说。", "我们走吧！"]);
+    assert_eq!(processor::chinese("“你好。”我们走了。"), vec!["“你好。”", "我们走了。"]);
+
+    // an unclosed quotation does not hold back the boundaries after it
+    assert_eq!(processor::japanese("「行きます。帰ります。"), vec!["「行きます。", "帰ります。"]);
+    assert_eq!(processor::japanese("彼は「行く。明日は雨だ。 でも大丈夫。後で「はい」と言った。"), vec!["彼は「行く。", "明日は雨だ。", "でも大丈夫。", "後で「はい」と言った。"]);
+    // long quoted speech ends sentences at its own terminators
+    assert_eq!(processor::japanese("「行きます。明日も来る。今日は晴れ。」と言った。"), vec!["「行きます。", "明日も来る。", "今日は晴れ。」と言った。"]);
+    // a word merely starting with a verb of speech does not continue the sentence
+    assert_eq!(processor::chinese("他说：“你好。”道路很宽。"), vec!["他说：“你好。”", "道路很宽。"]);
+
+    let segmenter = processor::Segmenter::new(processor::config::japanese()).unwrap();
+    let text = "「行きます。」と言った。";
+    let sentences = segmenter.segment(text).unwrap();
+    assert_eq!(&text[sentences[0].span.clone()], text);
+    assert_eq!(sentences[0].terminator, Some('。'));
+}
+
+#[test]
+fn test_korean_endings() {
+    let text = "오늘은 날씨가 좋네요 산책하러 갈까요 좋아요";
+    let sentences = processor::Segmenter::new(processor::config::korean()).unwrap().segment(text).unwrap();
+    assert_eq!(sentences.iter().map(|sentence| sentence.text.as_str()).collect::<Vec<_>>(), vec!["오늘은 날씨가 좋네요", "산책하러 갈까요", "좋아요."]);
+    assert_eq!(sentences[0].terminator, None);
+    assert_eq!(&text[sentences[1].span.clone()], "산책하러 갈까요");
+
+    assert_eq!(processor::korean("Dr. Kim은 내일 옵니다. 회의는 3.5시간 걸렸다."), vec!["Dr. Kim은 내일 옵니다.", "회의는 3.5시간 걸렸다."]);
+    // only the listed abbreviations keep their period, so English sentences still end
+    assert_eq!(processor::korean("나는 서울에 산다. I love Seoul. It is big. 정말 좋다."), vec!["나는 서울에 산다.", "I love Seoul.", "It is big.", "정말 좋다."]);
+    assert_eq!(processor::korean("Samsung Co. 본사는 수원에 있다. Mr. Lee가 왔다."), vec!["Samsung Co. 본사는 수원에 있다.", "Mr. Lee가 왔다."]);
+    assert_eq!(processor::korean("그는 “좋다.”라고 말했다. 나도 동의했다."), vec!["그는 “좋다.”라고 말했다.", "나도 동의했다."]);
+    // a comma after the ending joins the clauses even though step 2 removes it
+    assert_eq!(processor::korean("비가 왔어요, 그래서 집에 있었다."), vec!["비가 왔어요 그래서 집에 있었다."]);
+    // contracted past and plain present forms end a sentence like the listed endings
+    assert_eq!(processor::korean("그가 왔다 그래서 갔다"), vec!["그가 왔다", "그래서 갔다."]);
+    assert_eq!(processor::korean("나는 학교에 간다 친구가 봤다 그리고 웃었다"), vec!["나는 학교에 간다", "친구가 봤다", "그리고 웃었다."]);
+    assert_eq!(processor::korean("사랑한다고 말했다"), vec!["사랑한다고 말했다."]);
+    assert_eq!(processor::korean("ㅋㅋㅋ 진짜 웃기다 \u{1100}\u{1161}."), vec!["ㅋㅋㅋ 진짜 웃기다 \u{1100}\u{1161}."]);
+}
+
+#[test]
+fn test_traditional_mongolian() {
+    let text = "ᠮᠣᠩᠭᠣᠯ ᠬᠡᠯᠡ\u{202F}ᠶᠢ ᠰᠤᠷᠤᠨ᠎ᠠ\u{1803} ᠲᠠ ᠶᠠᠭᠤ ᠬᠢᠵᠦ ᠪᠠᠢᠨ᠎ᠠ︖ ᠭᠠ\u{180B}ᠵᠠᠷ ᠰᠠᠢᠬᠠᠨ\u{1809} ᠨᠣᠮ\u{202F}ᠢ ᠤᠩᠰᠢ︕";
+    assert_eq!(processor::mongolian_traditional(text), vec![
+        "ᠮᠣᠩᠭᠣᠯ ᠬᠡᠯᠡ\u{202F}ᠶᠢ ᠰᠤᠷᠤᠨ᠎ᠠ\u{1803}",
+        "ᠲᠠ ᠶᠠᠭᠤ ᠬᠢᠵᠦ ᠪᠠᠢᠨ᠎ᠠ︖",
+        "ᠭᠠ\u{180B}ᠵᠠᠷ ᠰᠠᠢᠬᠠᠨ\u{1809}",
+        "ᠨᠣᠮ\u{202F}ᠢ ᠤᠩᠰᠢ︕",
+    ]);
+    // a narrow no-break space outside Mongolian words is white space as before
+    assert_eq!(processor::mongolian_traditional("ᠰᠠᠢᠨ \u{202F} ?"), vec!["ᠰᠠᠢᠨ?"]);
+
+    let mut mongolian = processor::config::mongolian_traditional();
+    mongolian.suffix_spaces = false;
+    let sentences = processor::Segmenter::new(mongolian).unwrap().segment("ᠨᠣᠮ\u{202F}ᠢ ᠤᠩᠰᠢ︕").unwrap();
+    assert_eq!(sentences[0].text, "ᠨᠣᠮ ᠢ ᠤᠩᠰᠢ︕");
+}
+
+#[test]
+fn test_symbols() {
+    let text = "It costs $5 or 4€ and 50% more at 20°C! 😀👍🏽 Ask @anna about #deals. 3+4=7 🇫🇷";
+    assert_eq!(processor::english(text), vec!["It costs 5 or 4 and 50 more at 20C!", "Ask anna about deals.", "347."]);
+
+    let segmenter = |symbols| processor::Segmenter::with_cleaning(processor::config::english(), processor::Cleaning { symbols, ..Default::default() }).unwrap();
+    let texts = |symbols| -> Vec<String> { segmenter(symbols).segment(text).unwrap().into_iter().map(|sentence| sentence.text).collect() };
+    assert_eq!(texts(processor::Symbols::all()), vec!["It costs $5 or 4€ and 50% more at 20°C! 😀👍🏽", "Ask @anna about #deals.", "3+4=7 🇫🇷."]);
+    assert_eq!(texts(processor::Symbols { currency: true, ..Default::default() })[0], "It costs $5 or 4€ and 50 more at 20C!");
+    assert_eq!(texts(processor::Symbols { emoji: true, ..Default::default() })[2], "347 🇫🇷.");
+
+    let sentences = segmenter(processor::Symbols::all()).segment("Great! 🎉🎉 See you. 👋").unwrap();
+    assert_eq!(sentences.iter().map(|sentence| sentence.text.as_str()).collect::<Vec<_>>(), vec!["Great! 🎉🎉", "See you. 👋"]);
+    assert_eq!(sentences[0].terminator, Some('!'));
+}
+
+#[test]
+fn test_cleaning() {
+    let text = "He said (quietly) nothing. 1. First item.";
+    let segment = |cleaning: processor::Cleaning| {
+        let segmenter = processor::Segmenter::with_cleaning(processor::config::english(), cleaning).unwrap();
+        segmenter.segment(text).unwrap().into_iter().map(|sentence| sentence.text).collect::<Vec<String>>()
+    };
+    assert_eq!(segment(processor::Cleaning::default()), processor::english(text));
+    assert_eq!(segment(processor::Cleaning { quotations: false, ..Default::default() })[0], "He said (quietly) nothing.");
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_segment_batch() {
+    let documents: Vec<String> = (0..200).map(|i| format!("Document {} starts here. Is it done? Almost", i)).collect();
+    let documents: Vec<&str> = documents.iter().map(String::as_str).collect();
+    let batches = processor::segment_batch("en", &documents).unwrap();
+    assert_eq!(batches.len(), documents.len());
+    for (document, sentences) in documents.iter().zip(&batches) {
+        let texts: Vec<String> = sentences.iter().map(|sentence| sentence.text.clone()).collect();
+        assert_eq!(texts, processor::english(document));
+    }
+    // later calls reuse the segmenter compiled by the first one
+    assert_eq!(processor::segment_batch("english", &documents).unwrap(), batches);
+    assert_eq!(processor::segment_batch("xx", &documents), Err(Error::UnknownLanguage("xx".to_string())));
+}
+
+#[test]
+fn test_paragraphs() {
+    let text = "Getting started\n\nInstall the crate. Run it\n \n\n\nthen read the guide.";
+    let segmenter = processor::Segmenter::new(processor::config::english()).unwrap();
+    let paragraphs = segmenter.segment_paragraphs(text).unwrap();
+    let texts: Vec<Vec<&str>> = paragraphs.iter()
+        .map(|paragraph| paragraph.sentences.iter().map(|sentence| sentence.text.as_str()).collect())
+        .collect();
+    assert_eq!(texts, vec![vec!["Getting started."], vec!["Install the crate.", "Run it."], vec!["then read the guide."]]);
+    assert_eq!(&text[paragraphs[1].span.clone()], "Install the crate. Run it");
+    assert_eq!(&text[paragraphs[2].sentences[0].span.clone()], "then read the guide.");
+
+    // without paragraphs the heading and the lowercase sentence are glued to their neighbours
+    assert_eq!(processor::english(text), vec!["Getting started Install the crate.", "Run it then read the guide."]);
+}
+
+#[test]
+fn test_newlines() {
+    use processor::Newlines;
+
+    let text = "Groceries\n- eggs\n- milk\nwe sang all night\nand danced till dawn\nThe end";
+    let segment = |newlines: Newlines| {
+        let segmenter = processor::Segmenter::new(processor::config::english()).unwrap()
+            .with_newlines(newlines)
+            .with_trailing_fragment(processor::TrailingFragment::Keep);
+        segmenter.segment(text).unwrap().into_iter().map(|sentence| sentence.text).collect::<Vec<String>>()
+    };
+    assert_eq!(segment(Newlines::Ignore), vec!["Groceries eggs milk we sang all night and danced till dawn The end"]);
+    assert_eq!(segment(Newlines::Boundary), vec!["Groceries", "eggs", "milk", "we sang all night", "and danced till dawn", "The end"]);
+    assert_eq!(segment(Newlines::Heuristic), vec!["Groceries", "eggs", "milk we sang all night and danced till dawn", "The end"]);
+}
+
+#[test]
+fn test_html() {
+    let html = "<!DOCTYPE html><html><head><title>Ignored. Title</title><style>p { color: red; }</style></head>\n<body><h1>Fish &amp; Chips</h1>\n<p>It&rsquo;s a <b>classic</b>\ndish. Try it<br>Served hot</p><script>var a = \"<p>x. y</p>\";</script>\n<ul><li>One &#8211; two</li><li>Three</li></ul><!-- a. b --></body></html>";
+    let cleaning = processor::Cleaning { non_alphabet: false, ..Default::default() };
+    let segmenter = processor::Segmenter::with_cleaning(processor::config::english(), cleaning).unwrap()
+        .with_trailing_fragment(processor::TrailingFragment::Keep);
+    let sentences = segmenter.segment_html(html).unwrap();
+    let texts: Vec<&str> = sentences.iter().map(|sentence| sentence.text.as_str()).collect();
+    assert_eq!(texts, vec!["Fish & Chips", "It\u{2019}s a classic dish.", "Try it", "Served hot", "One \u{2013} two", "Three"]);
+    assert_eq!(&html[sentences[0].span.clone()], "Fish &amp; Chips");
+    assert_eq!(&html[sentences[1].span.clone()], "It&rsquo;s a <b>classic</b>\ndish.");
+
+    // an unterminated tag before the next `<` and an over-long entity are text
+    let sentences = segmenter.segment_html("<p>1 <a 2 <b>bold</b> &amp &aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa;</p>").unwrap();
+    assert_eq!(sentences[0].text, "1 <a 2 bold &amp &aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa;");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let json = r#"{"alphabets": "a-zA-Z", "have_capital_letter": true, "period": ".", "question_mark": "?", "exclamation_mark": "!"}"#;
+    let config: processor::LanguageConfig = serde_json::from_str(json).unwrap();
+    assert!(config.other_punctuations.is_empty());
+    let round_trip: processor::LanguageConfig = serde_json::from_str(&serde_json::to_string(&processor::config::english()).unwrap()).unwrap();
+    assert_eq!(round_trip, processor::config::english());
+
+    let cleaning: processor::Cleaning = serde_json::from_str(r#"{"quotations": false}"#).unwrap();
+    assert_eq!(cleaning, processor::Cleaning { quotations: false, ..Default::default() });
+
+    let segmenter = processor::Segmenter::with_cleaning(config, cleaning).unwrap();
+    let sentences = segmenter.segment("Is it (really) done? Almost").unwrap();
+    let value = serde_json::to_value(&sentences).unwrap();
+    assert_eq!(value[0]["span"], serde_json::json!({"start": 0, "end": 20}));
+    assert_eq!(value[0]["terminator"], "?");
+    assert_eq!(value[1]["trailing_fragment"], "append-period");
+    let back: Vec<processor::Sentence> = serde_json::from_value(value).unwrap();
+    assert_eq!(back, sentences);
+}
+
+#[test]
+fn test_corpus_writers() {
+    let segmenter = processor::Segmenter::new(processor::config::english()).unwrap();
+    let first = segmenter.segment("It rained. We stayed in").unwrap();
+    let second = segmenter.segment("The sun came out.").unwrap();
+
+    let mut conllu = ConlluWriter::new(vec![]);
+    conllu.write_document("doc1", &first).unwrap();
+    conllu.write_document("doc2", &second).unwrap();
+    assert_eq!(String::from_utf8(conllu.into_inner()).unwrap(), "\
+# newdoc id = doc1
+# sent_id = doc1-1
+# text = It rained.
+1\tIt\t_\t_\t_\t_\t_\t_\t_\t_
+2\trained.\t_\t_\t_\t_\t_\t_\t_\t_
+
+# sent_id = doc1-2
+# text = We stayed in.
+1\tWe\t_\t_\t_\t_\t_\t_\t_\t_
+2\tstayed\t_\t_\t_\t_\t_\t_\t_\t_
+3\tin.\t_\t_\t_\t_\t_\t_\t_\t_
+
+# newdoc id = doc2
+# sent_id = doc2-1
+# text = The sun came out.
+1\tThe\t_\t_\t_\t_\t_\t_\t_\t_
+2\tsun\t_\t_\t_\t_\t_\t_\t_\t_
+3\tcame\t_\t_\t_\t_\t_\t_\t_\t_
+4\tout.\t_\t_\t_\t_\t_\t_\t_\t_
+
+");
+
+    let mut lines = LineWriter::new(vec![]);
+    lines.write_document(&first).unwrap();
+    lines.write_document(&second).unwrap();
+    assert_eq!(String::from_utf8(lines.into_inner()).unwrap(), "It rained.\nWe stayed in.\n\nThe sun came out.\n");
+}
+
+#[test]
+fn test_subtitles() {
+    let srt = "1\r\n00:00:01,000 --> 00:00:03,000\r\n<i>We went to the</i>\r\nmarket. It was\r\n\r\n2\r\n00:00:04,000 --> 00:00:06,000\r\nclosed. So we left.\r\n";
+    let segmenter = processor::Segmenter::new(processor::config::english()).unwrap();
+    let sentences = segmenter.segment_subtitles(srt).unwrap();
+    let texts: Vec<&str> = sentences.iter().map(|timed| timed.sentence.text.as_str()).collect();
+    assert_eq!(texts, vec!["We went to the market.", "It was closed.", "So we left."]);
+    assert_eq!(sentences[0].start, Duration::from_secs(1));
+    assert!(sentences[0].end > Duration::from_secs(2) && sentences[0].end < Duration::from_secs(3));
+    // "It" is the 23rd of 28 visible characters of the first cue, "closed." the first 7 of 19 of the second
+    assert_eq!((sentences[1].start.as_millis(), sentences[1].end.as_millis()), (2_571, 4_736));
+    assert_eq!(sentences[2].end, Duration::from_secs(6));
+    assert_eq!(&srt[sentences[1].sentence.span.clone()], "It was\r\n\r\n2\r\n00:00:04,000 --> 00:00:06,000\r\nclosed.");
+
+    let vtt = "WEBVTT\n\nNOTE a comment. Ignored\n\n00:01.000 --> 00:02.000 align:start\n<v Ann>Hi &amp; bye.</v>\n";
+    let sentences = segmenter.segment_subtitles(vtt).unwrap();
+    assert_eq!(sentences.len(), 1);
+    assert_eq!((sentences[0].sentence.text.as_str(), sentences[0].end), ("Hi bye.", Duration::from_secs(2)));
+
+    // a cue index ends the cue before it even without a blank line, while a number in the text stays
+    let srt = "1\n00:00:01,000 --> 00:00:02,000\nWe counted to\n10\n2\n00:00:02,000 --> 00:00:03,000\nand stopped.\n";
+    let sentences = segmenter.segment_subtitles(srt).unwrap();
+    assert_eq!(sentences.iter().map(|timed| timed.sentence.text.as_str()).collect::<Vec<_>>(), vec!["We counted to 10 and stopped."]);
+
+    assert!(matches!(segmenter.segment_subtitles("1\n00:00:01 --> 00:00:02\nHi."), Err(Error::InvalidSubtitle { line: 2, .. })));
+}
+
+#[cfg(feature = "markdown")]
+#[test]
+fn test_markdown() {
+    let text = "# Getting Started\n\nCall `foo.bar()` to begin. It is ***very*** easy!\n\n- First item\n- See <https://example.com/a.b>\n\n```\nlet x = a.b();\n```\n\n| Name | Value |\n|------|-------|\n| alpha | one |\n";
+    let segmenter = processor::Segmenter::new(processor::config::english()).unwrap()
+        .with_trailing_fragment(processor::TrailingFragment::Keep);
+    let sentences = segmenter.segment_markdown(text).unwrap();
+    let texts: Vec<&str> = sentences.iter().map(|sentence| sentence.text.as_str()).collect();
+    assert_eq!(texts, vec![
+        "Getting Started", "Call `foo.bar()` to begin.", "It is very easy!", "First item", "See https://example.com/a.b",
+        "Name", "Value", "alpha", "one",
+    ]);
+    assert_eq!(&text[sentences[2].span.clone()], "It is ***very*** easy!");
+}